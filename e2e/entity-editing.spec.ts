@@ -58,7 +58,7 @@ test.describe("Entity Editing via Binder", () => {
     await expect(characteristicsHeading).toBeVisible();
   });
 
-  test("entity field editing triggers save_entity IPC", async ({ page }) => {
+  test("entity field editing triggers save_entity_draft IPC", async ({ page }) => {
     // Click on entity
     await page.getByText("Elena Blackwood").click();
 
@@ -75,8 +75,8 @@ test.describe("Entity Editing via Binder", () => {
     // Wait for debounced auto-save (1.5s + buffer)
     await page.waitForTimeout(2500);
 
-    // Verify save_entity was called
-    const saveCalls = await getIpcCallsByCommand(page, "save_entity");
+    // Verify the autosave draft was written
+    const saveCalls = await getIpcCallsByCommand(page, "save_entity_draft");
     expect(saveCalls.length).toBeGreaterThan(0);
   });
 
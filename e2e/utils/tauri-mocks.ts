@@ -527,6 +527,7 @@ export async function setupDefaultTauriMocks(
       body: "",
     })) as MockHandler,
     save_entity: null,
+    save_entity_draft: null,
     delete_entity: null,
     rename_entity: null,
     save_schema: null,
@@ -603,6 +604,15 @@ export async function setupDefaultTauriMocks(
       body: "",
     })) as MockHandler,
     save_notes_config: null,
+    default_note_palette: [
+      { name: "red", hex: "#ef4444" },
+      { name: "amber", hex: "#f59e0b" },
+      { name: "green", hex: "#22c55e" },
+      { name: "blue", hex: "#3b82f6" },
+      { name: "violet", hex: "#8b5cf6" },
+      { name: "pink", hex: "#ec4899" },
+      { name: "gray", hex: "#6b7280" },
+    ],
 
     // --- Session commands ---
     start_session: "2026-02-15T16:00:00Z",
@@ -1,10 +1,17 @@
+use crate::commands::search::{
+    link_names_file, linkable_files, split_link_kind, wiki_link_matches, wiki_link_spans,
+    LinkableFile,
+};
+use crate::commands::stats::strip_markdown;
 use crate::error::AppError;
 use crate::models::compile::{
-    ChapterHeaderStyle, ChapterSeparator, CompileConfig, CompileOutput, OutputFormat,
+    BodyMode, ChapterHeaderStyle, ChapterNumbering, ChapterSeparator, CompileConfig, CompileOutput,
+    NumberStyle, OutputFormat,
 };
 use crate::models::manuscript::ChapterFrontmatter;
 use crate::services::frontmatter;
 
+use std::ops::Range;
 use std::path::PathBuf;
 
 /// Helper: path to manuscript directory.
@@ -23,12 +30,12 @@ fn chapter_path(project_path: &str, slug: &str) -> PathBuf {
 }
 
 /// Count words by splitting on whitespace and counting non-empty tokens.
-fn count_words(text: &str) -> usize {
+pub(crate) fn count_words(text: &str) -> usize {
     text.split_whitespace().count()
 }
 
 /// Embedded CSS stylesheet for HTML export with print-ready formatting.
-const HTML_STYLESHEET: &str = r#"
+pub(crate) const HTML_STYLESHEET: &str = r#"
     /* Base typography */
     body {
         font-family: Georgia, 'Times New Roman', 'Noto Serif', serif;
@@ -77,6 +84,14 @@ const HTML_STYLESHEET: &str = r#"
         text-indent: 0;
     }
 
+    /* Drop caps */
+    .dropcap {
+        float: left;
+        font-size: 3.4em;
+        line-height: 0.85;
+        padding: 0.05em 0.08em 0 0;
+    }
+
     /* Separators */
     hr {
         border: none;
@@ -126,18 +141,121 @@ const HTML_STYLESHEET: &str = r#"
     }
 "#;
 
-/// Convert a compiled Markdown document to a full HTML document with embedded styles.
+/// Opening quotation marks that share a drop cap with the letter they precede.
+const OPENING_QUOTES: &[&str] = &["\"", "'", "\u{201C}", "\u{2018}", "\u{00AB}", "\u{201E}"];
+
+/// Split off the drop-cap text at the start of a paragraph: the first grapheme
+/// cluster, plus the next one when the first is an opening quotation mark.
+/// Works on graphemes so accented and combining letters stay whole.
+fn split_dropcap(text: &str) -> (&str, &str) {
+    use unicode_segmentation::UnicodeSegmentation;
+
+    let mut graphemes = text.grapheme_indices(true);
+    let Some((_, first)) = graphemes.next() else {
+        return ("", text);
+    };
+    let mut end = first.len();
+    if OPENING_QUOTES.contains(&first) {
+        if let Some((i, next)) = graphemes.next() {
+            end = i + next.len();
+        }
+    }
+    text.split_at(end)
+}
+
+/// Wrap the opening of each chapter's first body paragraph in
+/// `<span class="dropcap">`.
+///
+/// `chapter_starts` are the byte offsets where chapters begin, in order, so
+/// `##` headings inside a chapter don't get one. Paragraphs made up of a
+/// single emphasis run (chapter synopses) are passed over; a first paragraph
+/// that doesn't open with plain text gets no drop cap.
+fn add_dropcaps<'a>(
+    events: Vec<(pulldown_cmark::Event<'a>, Range<usize>)>,
+    chapter_starts: &[usize],
+) -> Vec<pulldown_cmark::Event<'a>> {
+    use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+
+    let is_synopsis = |i: usize| {
+        matches!(events.get(i + 1), Some((Event::Start(Tag::Emphasis), _)))
+            && events[i + 1..]
+                .iter()
+                .position(|(e, _)| matches!(e, Event::End(TagEnd::Emphasis)))
+                .is_some_and(|end| {
+                    matches!(
+                        events.get(i + end + 2),
+                        Some((Event::End(TagEnd::Paragraph), _))
+                    )
+                })
+    };
+
+    let mut out = Vec::with_capacity(events.len() + 2);
+    let mut starts = chapter_starts.iter().peekable();
+    let mut pending = false;
+    let mut iter = events.iter().enumerate();
+    while let Some((i, (event, range))) = iter.next() {
+        while starts.next_if(|&&start| start <= range.start).is_some() {
+            pending = true;
+        }
+        out.push(event.clone());
+        match event {
+            Event::Start(Tag::Paragraph) if pending && !is_synopsis(i) => {
+                pending = false;
+                // Smart punctuation yields quotes as separate text events, so
+                // join the paragraph's leading text run before splitting it.
+                let mut text = String::new();
+                let mut next = i + 1;
+                while let Some((Event::Text(t), _)) = events.get(next) {
+                    text.push_str(t);
+                    iter.next();
+                    next += 1;
+                }
+                if !text.is_empty() {
+                    let (cap, rest) = split_dropcap(&text);
+                    out.push(Event::InlineHtml(CowStr::from(format!(
+                        "<span class=\"dropcap\">{}</span>",
+                        html_escape(cap)
+                    ))));
+                    out.push(Event::Text(CowStr::from(rest.to_string())));
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Convert Markdown to an HTML fragment with `pulldown-cmark`.
 ///
-/// Uses `pulldown-cmark` for Markdown-to-HTML conversion, then wraps the result
-/// in a complete HTML document with DOCTYPE, head (including the embedded CSS), and body.
-fn render_html(markdown: &str, title: &str) -> String {
+/// The first paragraph of each chapter starting at one of the 0-based
+/// `dropcap_lines` opens with a drop cap.
+pub(crate) fn markdown_to_html(markdown: &str, dropcap_lines: &[usize]) -> String {
     use pulldown_cmark::{html, Options, Parser};
 
     let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_SMART_PUNCTUATION;
     let parser = Parser::new_ext(markdown, options);
+    let events: Vec<_> = if dropcap_lines.is_empty() {
+        parser.collect()
+    } else {
+        let offsets = line_offsets(markdown);
+        let starts: Vec<usize> = dropcap_lines
+            .iter()
+            .filter_map(|&line| offsets.get(line).copied())
+            .collect();
+        add_dropcaps(parser.into_offset_iter().collect(), &starts)
+    };
 
     let mut html_body = String::new();
-    html::push_html(&mut html_body, parser);
+    html::push_html(&mut html_body, events.into_iter());
+    html_body
+}
+
+/// Convert a compiled Markdown document to a full HTML document with embedded styles.
+///
+/// Converts the Markdown with `markdown_to_html`, then wraps the result in a
+/// complete HTML document with DOCTYPE, head (including the embedded CSS), and body.
+fn render_html(markdown: &str, title: &str, dropcap_lines: &[usize]) -> String {
+    let html_body = markdown_to_html(markdown, dropcap_lines);
 
     format!(
         r#"<!DOCTYPE html>
@@ -159,7 +277,7 @@ fn render_html(markdown: &str, title: &str) -> String {
 }
 
 /// Escape special HTML characters in a string for safe embedding in HTML attributes/content.
-fn html_escape(s: &str) -> String {
+pub(crate) fn html_escape(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
@@ -293,6 +411,170 @@ fn render_plain_text(markdown: &str, separator: &ChapterSeparator) -> String {
     output.trim_end().to_string()
 }
 
+/// Escape LaTeX special characters (`& % $ # _ { } ~ ^ \`) in a text fragment.
+fn latex_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\textbackslash{}"),
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Byte offset where each line of `markdown` starts, indexed by 0-based line.
+fn line_offsets(markdown: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(markdown.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+/// Convert a compiled Markdown document to a LaTeX `book` document.
+///
+/// Uses `pulldown-cmark` to parse the Markdown AST, then walks the events to produce:
+/// - A `\documentclass{book}` preamble carrying the escaped title and author
+/// - The compiled title page (the 0-based lines in `title_page`) replaced by
+///   `\maketitle`, whatever Markdown the title and author happen to form
+/// - `##` chapter headers as `\chapter{...}` (starred when the header text already
+///   carries a chapter number, so LaTeX does not number it twice)
+/// - Bold/italic as `\textbf`/`\emph`, in headings as well as body text
+/// - Separators rendered according to the configured chapter separator style
+/// - All LaTeX special characters in text escaped
+fn render_latex(
+    markdown: &str,
+    config: &CompileConfig,
+    title_page: Option<Range<usize>>,
+) -> String {
+    use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+    let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_SMART_PUNCTUATION;
+    let parser = Parser::new_ext(markdown, options);
+
+    let chapter_command = match config.chapter_header_style {
+        ChapterHeaderStyle::Titled => "\\chapter",
+        _ => "\\chapter*",
+    };
+
+    // Byte range of the title page's lines.
+    let title_page = title_page.map(|lines| {
+        let offsets = line_offsets(markdown);
+        let offset = |line: usize| offsets.get(line).copied().unwrap_or(markdown.len());
+        offset(lines.start)..offset(lines.end)
+    });
+
+    let mut body = String::new();
+    let mut made_title = false;
+    let mut heading_level: Option<HeadingLevel> = None;
+    let mut heading_text = String::new();
+
+    for (event, range) in parser.into_offset_iter() {
+        if title_page
+            .as_ref()
+            .is_some_and(|r| r.contains(&range.start))
+        {
+            if !made_title {
+                made_title = true;
+                body.push_str("\\maketitle\n\n");
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                heading_level = Some(level);
+                heading_text.clear();
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                // Already escaped as it was collected
+                let text = heading_text.trim();
+                match heading_level {
+                    Some(HeadingLevel::H1) => {
+                        body.push_str(&format!("\\part*{{{}}}\n\n", text));
+                    }
+                    Some(HeadingLevel::H2) => {
+                        body.push_str(&format!("{}{{{}}}\n\n", chapter_command, text));
+                    }
+                    _ => {
+                        body.push_str(&format!("\\section*{{{}}}\n\n", text));
+                    }
+                }
+                heading_level = None;
+            }
+            event => {
+                let in_heading = heading_level.is_some();
+                let out = if in_heading {
+                    &mut heading_text
+                } else {
+                    &mut body
+                };
+                match event {
+                    Event::Start(Tag::Strong) => out.push_str("\\textbf{"),
+                    Event::End(TagEnd::Strong) => out.push('}'),
+                    Event::Start(Tag::Emphasis) => out.push_str("\\emph{"),
+                    Event::End(TagEnd::Emphasis) => out.push('}'),
+                    Event::Start(Tag::Paragraph) => {}
+                    Event::End(TagEnd::Paragraph) => out.push_str("\n\n"),
+                    Event::Start(Tag::BlockQuote(_)) => out.push_str("\\begin{quote}\n"),
+                    Event::End(TagEnd::BlockQuote(_)) => out.push_str("\\end{quote}\n\n"),
+                    Event::Start(Tag::List(_)) => out.push_str("\\begin{itemize}\n"),
+                    Event::End(TagEnd::List(_)) => out.push_str("\\end{itemize}\n\n"),
+                    Event::Start(Tag::Item) => out.push_str("\\item "),
+                    Event::End(TagEnd::Item) => out.push('\n'),
+                    Event::Text(text) => out.push_str(&latex_escape(&text)),
+                    Event::Code(text) => {
+                        out.push_str(&format!("\\texttt{{{}}}", latex_escape(&text)))
+                    }
+                    Event::SoftBreak if in_heading => out.push(' '),
+                    Event::SoftBreak => out.push('\n'),
+                    Event::HardBreak if in_heading => out.push(' '),
+                    Event::HardBreak => out.push_str("\\\\\n"),
+                    Event::Rule => {
+                        let rule_text = match config.chapter_separator {
+                            ChapterSeparator::ThreeStars => {
+                                "\\bigskip\n\\begin{center}*\\quad*\\quad*\\end{center}\n\\bigskip"
+                            }
+                            ChapterSeparator::HorizontalRule => {
+                                "\\bigskip\n\\begin{center}\\rule{0.5\\textwidth}{0.4pt}\\end{center}\n\\bigskip"
+                            }
+                            ChapterSeparator::PageBreak => "\\clearpage",
+                            ChapterSeparator::BlankLines => "\\bigskip",
+                        };
+                        out.push_str(rule_text);
+                        out.push_str("\n\n");
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    format!(
+        "\\documentclass{{book}}\n\
+         \\usepackage[utf8]{{inputenc}}\n\
+         \\usepackage[T1]{{fontenc}}\n\
+         \n\
+         \\title{{{title}}}\n\
+         \\author{{{author}}}\n\
+         \\date{{}}\n\
+         \n\
+         \\begin{{document}}\n\
+         \n\
+         {body}\n\
+         \n\
+         \\end{{document}}\n",
+        title = latex_escape(&config.title),
+        author = latex_escape(&config.author),
+        body = body.trim_end(),
+    )
+}
+
 /// Format the separator string for a given ChapterSeparator variant.
 fn separator_string(sep: &ChapterSeparator) -> &'static str {
     match sep {
@@ -303,8 +585,104 @@ fn separator_string(sep: &ChapterSeparator) -> &'static str {
     }
 }
 
-/// Generate a chapter header line based on the style, chapter number, and title.
-fn chapter_header(style: &ChapterHeaderStyle, number: usize, title: &str) -> Option<String> {
+/// Roman numeral for 1–3999; `None` outside that range.
+fn to_roman(n: i64) -> Option<String> {
+    const NUMERALS: [(i64, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    if !(1..=3999).contains(&n) {
+        return None;
+    }
+    let mut rest = n;
+    let mut out = String::new();
+    for (value, numeral) in NUMERALS {
+        while rest >= value {
+            out.push_str(numeral);
+            rest -= value;
+        }
+    }
+    Some(out)
+}
+
+/// Capitalised English words for 0–999 ("Twenty-One"); `None` outside that range.
+fn to_words(n: i64) -> Option<String> {
+    const ONES: [&str; 20] = [
+        "Zero",
+        "One",
+        "Two",
+        "Three",
+        "Four",
+        "Five",
+        "Six",
+        "Seven",
+        "Eight",
+        "Nine",
+        "Ten",
+        "Eleven",
+        "Twelve",
+        "Thirteen",
+        "Fourteen",
+        "Fifteen",
+        "Sixteen",
+        "Seventeen",
+        "Eighteen",
+        "Nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "Twenty", "Thirty", "Forty", "Fifty", "Sixty", "Seventy", "Eighty", "Ninety",
+    ];
+    let below_hundred = |n: usize| match n {
+        0..=19 => ONES[n].to_string(),
+        _ if n.is_multiple_of(10) => TENS[n / 10].to_string(),
+        _ => format!("{}-{}", TENS[n / 10], ONES[n % 10]),
+    };
+
+    if !(0..=999).contains(&n) {
+        return None;
+    }
+    let n = n as usize;
+    Some(match (n / 100, n % 100) {
+        (0, rest) => below_hundred(rest),
+        (hundreds, 0) => format!("{} Hundred", ONES[hundreds]),
+        (hundreds, rest) => format!("{} Hundred {}", ONES[hundreds], below_hundred(rest)),
+    })
+}
+
+/// Render the number of the chapter at 1-based `position` in the compile.
+///
+/// Numbers a style can't express (zero or negative in roman, beyond the word
+/// range) fall back to arabic digits.
+fn format_chapter_number(numbering: &ChapterNumbering, position: usize) -> String {
+    let number = numbering.start + position as i64 - 1;
+    let formatted = match numbering.style {
+        NumberStyle::Arabic => None,
+        NumberStyle::Roman => to_roman(number),
+        NumberStyle::Words => to_words(number),
+    };
+    formatted.unwrap_or_else(|| number.to_string())
+}
+
+/// Generate a chapter header line based on the style, the chapter's 1-based
+/// position in the compile, and its title.
+fn chapter_header(
+    style: &ChapterHeaderStyle,
+    numbering: &ChapterNumbering,
+    position: usize,
+    title: &str,
+) -> Option<String> {
+    let number = format_chapter_number(numbering, position);
     match style {
         ChapterHeaderStyle::Numbered => Some(format!("## Chapter {}", number)),
         ChapterHeaderStyle::Titled => Some(format!("## {}", title)),
@@ -313,6 +691,258 @@ fn chapter_header(style: &ChapterHeaderStyle, number: usize, title: &str) -> Opt
     }
 }
 
+/// Append one chapter's header, optional synopsis and body to `output`,
+/// returning the header line that was written, if any.
+fn push_chapter(
+    output: &mut String,
+    config: &CompileConfig,
+    chapter_number: usize,
+    doc: &frontmatter::ParsedDocument<ChapterFrontmatter>,
+) -> Option<String> {
+    let synopsis = doc
+        .frontmatter
+        .synopsis
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .filter(|_| match config.body_mode {
+            BodyMode::Full => config.include_synopsis,
+            BodyMode::SynopsisOnly => true,
+            BodyMode::TitlesOnly => false,
+        });
+    let body = match config.body_mode {
+        BodyMode::Full => doc.body.as_str(),
+        BodyMode::SynopsisOnly | BodyMode::TitlesOnly => "",
+    };
+
+    // Chapter header
+    let header = chapter_header(
+        &config.chapter_header_style,
+        &config.chapter_numbering,
+        chapter_number,
+        &doc.frontmatter.title,
+    );
+    if let Some(ref header) = header {
+        output.push_str(header);
+        output.push('\n');
+        // Check if there is a synopsis or body to add after the header
+        if synopsis.is_some() || !body.is_empty() {
+            output.push('\n');
+        }
+    }
+
+    // Synopsis
+    if let Some(synopsis) = synopsis {
+        output.push_str(&format!("*{}*", synopsis));
+        output.push('\n');
+        if !body.is_empty() {
+            output.push('\n');
+        }
+    }
+
+    // Body
+    output.push_str(body);
+
+    header
+}
+
+/// A compiled chapter that HTML wiki-links can point at.
+struct ChapterAnchor {
+    slug: String,
+    title: String,
+    /// The Markdown header line emitted for the chapter, if any.
+    header: Option<String>,
+    /// 0-based line of the compiled Markdown where the chapter starts, used to
+    /// place drop caps.
+    start_line: usize,
+}
+
+impl ChapterAnchor {
+    /// Whether a wiki-link target names this chapter, by the same rule as
+    /// `wiki_link_matches`; a `chapter:` prefix is allowed.
+    fn matches_link(&self, target: &str) -> bool {
+        let (_, title) = split_link_kind(target, |kind| kind == "chapter");
+        link_names_file(&self.title, &self.slug, title)
+    }
+}
+
+/// Anchor id given to a chapter's heading in HTML output.
+fn chapter_anchor_id(slug: &str) -> String {
+    format!("chap-{}", slug)
+}
+
+/// Replace every `[[target]]` in `line` with an anchor to the compiled chapter
+/// whose slug or title (case-insensitive) matches, or with the bare target text.
+fn link_wiki_references(line: &str, chapters: &[&ChapterAnchor]) -> String {
+    rewrite_wiki_links(line, |target| {
+        Some(match chapters.iter().find(|c| c.matches_link(target)) {
+            Some(chapter) => format!(
+                "<a href=\"#{}\">{}</a>",
+                chapter_anchor_id(&chapter.slug),
+                html_escape(&chapter.title)
+            ),
+            None => target.to_string(),
+        })
+    })
+}
+
+/// Pre-pass for HTML output with `resolve_links`: give each chapter heading an
+/// `id` and turn wiki-links into in-document anchors.
+///
+/// Chapter headers are matched in order against the lines `push_chapter`
+/// emitted and rewritten as `<h2 id="chap-{slug}">`. Links to chapters without
+/// a heading (outside the compile, or with header style `None`) become plain text.
+fn resolve_chapter_links(markdown: &str, chapters: &[ChapterAnchor]) -> String {
+    let anchored: Vec<&ChapterAnchor> = chapters.iter().filter(|c| c.header.is_some()).collect();
+    let mut next = 0;
+    let mut out = String::with_capacity(markdown.len());
+
+    for line in markdown.split_inclusive('\n') {
+        let text = line.trim_end_matches('\n');
+        let newline = &line[text.len()..];
+        match anchored.get(next) {
+            Some(chapter) if chapter.header.as_deref() == Some(text) => {
+                let heading = text.trim_start_matches('#').trim();
+                out.push_str(&format!(
+                    "<h2 id=\"{}\">{}</h2>",
+                    chapter_anchor_id(&chapter.slug),
+                    html_escape(heading)
+                ));
+                next += 1;
+            }
+            _ => out.push_str(&link_wiki_references(text, &anchored)),
+        }
+        out.push_str(newline);
+    }
+    out
+}
+
+/// Replace every `[[target]]` in `markdown` with what `rewrite` returns for
+/// its trimmed target text; links for which it returns `None` are left as written.
+pub(crate) fn rewrite_wiki_links(
+    markdown: &str,
+    rewrite: impl Fn(&str) -> Option<String>,
+) -> String {
+    let mut out = String::with_capacity(markdown.len());
+    for line in markdown.split_inclusive('\n') {
+        let mut copied = 0;
+        for (span, target) in wiki_link_spans(line) {
+            if let Some(replacement) = rewrite(target.trim()) {
+                out.push_str(&line[copied..span.start]);
+                out.push_str(&replacement);
+                copied = span.end;
+            }
+        }
+        out.push_str(&line[copied..]);
+    }
+    out
+}
+
+/// Replace every `[[target]]` in `markdown` with the title of the file it
+/// resolves to, or with the bare target text when it resolves to nothing.
+///
+/// Links for which `keep` returns true are left as written for a later pass.
+fn render_wiki_link_titles(
+    markdown: &str,
+    files: &[LinkableFile],
+    keep: impl Fn(&str) -> bool,
+) -> String {
+    rewrite_wiki_links(markdown, |target| {
+        if keep(target) {
+            return None;
+        }
+        // Ambiguous matches share a title, so the first one will do.
+        Some(match wiki_link_matches(files, target).first() {
+            Some(file) => file.title.clone(),
+            None => target.to_string(),
+        })
+    })
+}
+
+/// Apply `render_wiki_links` to compiled Markdown.
+///
+/// When HTML output also resolves chapter links, links to anchored chapters
+/// are kept for `resolve_chapter_links` to turn into anchors.
+fn render_compiled_wiki_links(
+    content: String,
+    project_path: &str,
+    config: &CompileConfig,
+    chapters: &[ChapterAnchor],
+) -> String {
+    if !config.render_wiki_links {
+        return content;
+    }
+    let files = linkable_files(&PathBuf::from(project_path));
+    let chapter_links = config.output_format == OutputFormat::Html && config.resolve_links;
+    render_wiki_link_titles(&content, &files, |target| {
+        chapter_links
+            && chapters
+                .iter()
+                .any(|c| c.header.is_some() && c.matches_link(target))
+    })
+}
+
+/// Characters per line and lines per page in standard manuscript format
+/// (12pt Courier, double-spaced, one-inch margins).
+const MANUSCRIPT_LINE_CHARS: usize = 60;
+const MANUSCRIPT_PAGE_LINES: usize = 25;
+
+/// Reject a `words_per_page` that can't produce a page estimate.
+fn validate_words_per_page(config: &CompileConfig) -> Result<(), AppError> {
+    if config.words_per_page == 0 {
+        return Err(AppError::Validation(
+            "Words per page must be greater than zero".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Pages needed for `word_count` words at `words_per_page`, rounded up.
+fn page_estimate(word_count: usize, words_per_page: u32) -> usize {
+    word_count.div_ceil(words_per_page as usize)
+}
+
+/// Standard-manuscript page count for compiled Markdown, or `None` when the
+/// compile leaves out chapter bodies.
+///
+/// Each paragraph starts on a new line and wraps every 60 characters.
+fn manuscript_page_estimate(markdown: &str, config: &CompileConfig) -> Option<usize> {
+    if config.body_mode != BodyMode::Full {
+        return None;
+    }
+    let lines: usize = strip_markdown(markdown)
+        .lines()
+        .map(|line| line.trim().chars().count().div_ceil(MANUSCRIPT_LINE_CHARS))
+        .sum();
+    Some(lines.div_ceil(MANUSCRIPT_PAGE_LINES))
+}
+
+/// Convert compiled Markdown into the configured output format.
+///
+/// `title_page` is the range of 0-based lines holding the compiled title page, if any.
+fn render_output(
+    content: String,
+    config: &CompileConfig,
+    chapters: &[ChapterAnchor],
+    title_page: Option<Range<usize>>,
+) -> String {
+    let dropcap_lines: Vec<usize> = if config.dropcaps {
+        chapters.iter().map(|c| c.start_line).collect()
+    } else {
+        Vec::new()
+    };
+    match config.output_format {
+        OutputFormat::Html if config.resolve_links => render_html(
+            &resolve_chapter_links(&content, chapters),
+            &config.title,
+            &dropcap_lines,
+        ),
+        OutputFormat::Html => render_html(&content, &config.title, &dropcap_lines),
+        OutputFormat::PlainText => render_plain_text(&content, &config.chapter_separator),
+        OutputFormat::Latex => render_latex(&content, config, title_page),
+        OutputFormat::Markdown => content,
+    }
+}
+
 /// Compile the full manuscript into a single document string.
 ///
 /// Pipeline:
@@ -330,6 +960,8 @@ pub fn compile_manuscript(
     use crate::models::manuscript::ManuscriptConfig;
     use crate::services::yaml_service::read_yaml;
 
+    validate_words_per_page(&config)?;
+
     // 1. Read manuscript config
     let manuscript_config: ManuscriptConfig = {
         let path = config_path(&project_path);
@@ -346,9 +978,11 @@ pub fn compile_manuscript(
     if slugs.is_empty() {
         return Ok(CompileOutput {
             content: String::new(),
+            manuscript_page_estimate: manuscript_page_estimate("", &config),
             format: config.output_format,
             chapter_count: 0,
             word_count: 0,
+            page_estimate: 0,
         });
     }
 
@@ -361,15 +995,19 @@ pub fn compile_manuscript(
     }
 
     // 3b. Title page
-    if config.include_title_page {
+    let title_page = config.include_title_page.then(|| {
+        let first_line = output.matches('\n').count();
         output.push_str(&format!("# {}\n\n", config.title));
         output.push_str(&format!("**{}**", config.author));
+        let lines = first_line..output.matches('\n').count() + 1;
         output.push_str(separator_string(&config.chapter_separator));
-    }
+        lines
+    });
 
     // 2. Load each chapter, skip missing ones gracefully
     let mut chapter_count: usize = 0;
     let mut chapter_number: usize = 0;
+    let mut anchors: Vec<ChapterAnchor> = Vec::new();
 
     for (i, slug) in slugs.iter().enumerate() {
         let path = chapter_path(&project_path, slug);
@@ -404,48 +1042,14 @@ pub fn compile_manuscript(
         chapter_number += 1;
         chapter_count += 1;
 
-        // Chapter header
-        if let Some(header) = chapter_header(
-            &config.chapter_header_style,
-            chapter_number,
-            &doc.frontmatter.title,
-        ) {
-            output.push_str(&header);
-            output.push('\n');
-            // Check if there is a synopsis or body to add after the header
-            let has_synopsis = config.include_synopsis
-                && doc
-                    .frontmatter
-                    .synopsis
-                    .as_ref()
-                    .is_some_and(|s| !s.is_empty());
-            let has_body = !doc.body.is_empty();
-            if has_synopsis || has_body {
-                output.push('\n');
-            }
-        }
-
-        // Synopsis
-        if config.include_synopsis {
-            if let Some(ref synopsis) = doc.frontmatter.synopsis {
-                if !synopsis.is_empty() {
-                    output.push_str(&format!("*{}*", synopsis));
-                    output.push('\n');
-                    if !doc.body.is_empty() {
-                        output.push('\n');
-                    }
-                }
-            }
-        }
-
-        // Body
-        if !doc.body.is_empty() {
-            output.push_str(&doc.body);
-            // Ensure no trailing newline duplication - body may already end with newline
-            if !doc.body.ends_with('\n') {
-                // don't add; the body as-is is fine
-            }
-        }
+        let start_line = output.matches('\n').count();
+        let header = push_chapter(&mut output, &config, chapter_number, &doc);
+        anchors.push(ChapterAnchor {
+            slug: slug.clone(),
+            title: doc.frontmatter.title.clone(),
+            header,
+            start_line,
+        });
 
         // Remove trailing whitespace from the last chapter's contribution
         // We'll trim the whole output at the end
@@ -454,21 +1058,80 @@ pub fn compile_manuscript(
 
     // Trim trailing whitespace from the entire output
     let content = output.trim_end().to_string();
+    let content = render_compiled_wiki_links(content, &project_path, &config, &anchors);
 
     let word_count = count_words(&content);
+    let manuscript_pages = manuscript_page_estimate(&content, &config);
 
     // Post-process: convert Markdown to the requested output format
-    let final_content = match config.output_format {
-        OutputFormat::Html => render_html(&content, &config.title),
-        OutputFormat::PlainText => render_plain_text(&content, &config.chapter_separator),
-        OutputFormat::Markdown => content,
-    };
+    let final_content = render_output(content, &config, &anchors, title_page);
 
     Ok(CompileOutput {
         content: final_content,
         format: config.output_format,
         chapter_count,
         word_count,
+        page_estimate: page_estimate(word_count, config.words_per_page),
+        manuscript_page_estimate: manuscript_pages,
+    })
+}
+
+/// Compile a single chapter on its own: header, optional synopsis and body,
+/// with no front matter, title page or separators.
+///
+/// Numbered headers use the chapter's position in the manuscript order.
+#[tauri::command]
+pub fn compile_chapter(
+    project_path: String,
+    slug: String,
+    config: CompileConfig,
+) -> Result<CompileOutput, AppError> {
+    use crate::models::manuscript::ManuscriptConfig;
+    use crate::services::yaml_service::read_yaml;
+
+    validate_words_per_page(&config)?;
+
+    let path = chapter_path(&project_path, &slug);
+    if !path.exists() {
+        return Err(AppError::NotFound(format!("Chapter not found: {}", slug)));
+    }
+
+    let content = std::fs::read_to_string(&path)?;
+    let doc: frontmatter::ParsedDocument<ChapterFrontmatter> = frontmatter::parse(&content)?;
+
+    let chapter_number = if config_path(&project_path).exists() {
+        let manuscript_config: ManuscriptConfig = read_yaml(&config_path(&project_path))?;
+        manuscript_config
+            .chapters
+            .iter()
+            .position(|s| *s == slug)
+            .map_or(1, |i| i + 1)
+    } else {
+        1
+    };
+
+    let mut output = String::new();
+    let header = push_chapter(&mut output, &config, chapter_number, &doc);
+    let anchor = ChapterAnchor {
+        slug,
+        title: doc.frontmatter.title,
+        header,
+        start_line: 0,
+    };
+
+    let anchors = [anchor];
+    let content = output.trim_end().to_string();
+    let content = render_compiled_wiki_links(content, &project_path, &config, &anchors);
+    let word_count = count_words(&content);
+    let manuscript_pages = manuscript_page_estimate(&content, &config);
+
+    Ok(CompileOutput {
+        content: render_output(content, &config, &anchors, None),
+        format: config.output_format,
+        chapter_count: 1,
+        word_count,
+        page_estimate: page_estimate(word_count, config.words_per_page),
+        manuscript_page_estimate: manuscript_pages,
     })
 }
 
@@ -531,6 +1194,12 @@ mod tests {
             output_format: OutputFormat::Markdown,
             include_synopsis: false,
             front_matter: String::new(),
+            chapter_numbering: ChapterNumbering::default(),
+            body_mode: BodyMode::Full,
+            resolve_links: false,
+            dropcaps: false,
+            words_per_page: 250,
+            render_wiki_links: false,
         }
     }
 
@@ -844,6 +1513,88 @@ mod tests {
         assert!(!result.content.contains("*\n"));
     }
 
+    // ── Body mode ──────────────────────────────────────────────────
+
+    /// Helper: two chapters with synopses and multi-word bodies.
+    fn write_outline_fixture(pp: &str) {
+        write_config(pp, &["ch-1", "ch-2"]);
+        write_chapter(
+            pp,
+            "ch-1",
+            "Arrival",
+            Some("She reaches the city"),
+            "The gates were open and nobody watched them.",
+        );
+        write_chapter(pp, "ch-2", "Departure", None, "He left before dawn.");
+    }
+
+    #[test]
+    fn body_mode_full_includes_bodies() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_outline_fixture(&pp);
+
+        let result = compile_manuscript(pp, default_config()).unwrap();
+        assert!(result.content.contains("The gates were open"));
+        assert!(result.content.contains("He left before dawn."));
+        assert!(!result.content.contains("She reaches the city"));
+    }
+
+    #[test]
+    fn body_mode_synopsis_only_skips_bodies() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_outline_fixture(&pp);
+
+        let mut config = default_config();
+        config.body_mode = BodyMode::SynopsisOnly;
+
+        let result = compile_manuscript(pp, config).unwrap();
+        assert_eq!(
+            result.content,
+            "## Arrival\n\n*She reaches the city*\n\n\n* * *\n\n## Departure"
+        );
+        // "Arrival", "She reaches the city", "Departure" plus "##" and "* * *" tokens
+        assert_eq!(result.word_count, count_words(&result.content));
+        assert_eq!(result.word_count, 11);
+        assert_eq!(result.chapter_count, 2);
+    }
+
+    #[test]
+    fn body_mode_synopsis_only_ignores_include_synopsis_flag() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_outline_fixture(&pp);
+
+        let mut config = default_config();
+        config.body_mode = BodyMode::SynopsisOnly;
+        config.include_synopsis = false;
+
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("*She reaches the city*"));
+    }
+
+    #[test]
+    fn body_mode_titles_only_emits_headers() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_outline_fixture(&pp);
+
+        let mut config = default_config();
+        config.body_mode = BodyMode::TitlesOnly;
+        config.include_synopsis = true;
+        config.chapter_header_style = ChapterHeaderStyle::NumberedAndTitled;
+
+        let result = compile_manuscript(pp, config).unwrap();
+        assert_eq!(
+            result.content,
+            "## Chapter 1: Arrival\n\n\n* * *\n\n## Chapter 2: Departure"
+        );
+        assert!(!result.content.contains("She reaches the city"));
+        assert!(!result.content.contains("dawn"));
+        assert_eq!(result.word_count, count_words(&result.content));
+    }
+
     // ── Missing chapters (graceful skip) ───────────────────────────
 
     #[test]
@@ -986,10 +1737,9 @@ mod tests {
             author: "A. Writer".to_string(),
             include_title_page: true,
             chapter_header_style: ChapterHeaderStyle::NumberedAndTitled,
-            chapter_separator: ChapterSeparator::ThreeStars,
-            output_format: OutputFormat::Markdown,
             include_synopsis: true,
             front_matter: "For those who dream.".to_string(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1064,7 +1814,12 @@ mod tests {
     #[test]
     fn test_chapter_header_numbered() {
         assert_eq!(
-            chapter_header(&ChapterHeaderStyle::Numbered, 5, "Ignored"),
+            chapter_header(
+                &ChapterHeaderStyle::Numbered,
+                &ChapterNumbering::default(),
+                5,
+                "Ignored"
+            ),
             Some("## Chapter 5".to_string())
         );
     }
@@ -1072,7 +1827,12 @@ mod tests {
     #[test]
     fn test_chapter_header_titled() {
         assert_eq!(
-            chapter_header(&ChapterHeaderStyle::Titled, 5, "My Title"),
+            chapter_header(
+                &ChapterHeaderStyle::Titled,
+                &ChapterNumbering::default(),
+                5,
+                "My Title"
+            ),
             Some("## My Title".to_string())
         );
     }
@@ -1080,18 +1840,102 @@ mod tests {
     #[test]
     fn test_chapter_header_numbered_and_titled() {
         assert_eq!(
-            chapter_header(&ChapterHeaderStyle::NumberedAndTitled, 3, "Dawn"),
+            chapter_header(
+                &ChapterHeaderStyle::NumberedAndTitled,
+                &ChapterNumbering::default(),
+                3,
+                "Dawn"
+            ),
             Some("## Chapter 3: Dawn".to_string())
         );
     }
 
     #[test]
     fn test_chapter_header_none() {
-        assert_eq!(chapter_header(&ChapterHeaderStyle::None, 1, "Title"), None);
+        assert_eq!(
+            chapter_header(
+                &ChapterHeaderStyle::None,
+                &ChapterNumbering::default(),
+                1,
+                "Title"
+            ),
+            None
+        );
     }
 
     #[test]
-    fn test_separator_string_values() {
+    fn test_to_roman_covers_common_chapter_numbers() {
+        let expected = [
+            (1, "I"),
+            (4, "IV"),
+            (9, "IX"),
+            (14, "XIV"),
+            (40, "XL"),
+            (44, "XLIV"),
+            (49, "XLIX"),
+            (50, "L"),
+            (1999, "MCMXCIX"),
+        ];
+        for (n, roman) in expected {
+            assert_eq!(to_roman(n).as_deref(), Some(roman), "roman for {}", n);
+        }
+        assert_eq!(to_roman(0), None);
+        assert_eq!(to_roman(-3), None);
+    }
+
+    #[test]
+    fn test_to_words_covers_common_chapter_numbers() {
+        let expected = [
+            (0, "Zero"),
+            (4, "Four"),
+            (13, "Thirteen"),
+            (20, "Twenty"),
+            (21, "Twenty-One"),
+            (38, "Thirty-Eight"),
+            (50, "Fifty"),
+            (100, "One Hundred"),
+            (342, "Three Hundred Forty-Two"),
+        ];
+        for (n, words) in expected {
+            assert_eq!(to_words(n).as_deref(), Some(words), "words for {}", n);
+        }
+        assert_eq!(to_words(1000), None);
+        assert_eq!(to_words(-1), None);
+    }
+
+    #[test]
+    fn test_chapter_header_roman_and_words() {
+        let roman = ChapterNumbering {
+            style: NumberStyle::Roman,
+            start: 1,
+        };
+        assert_eq!(
+            chapter_header(&ChapterHeaderStyle::NumberedAndTitled, &roman, 4, "Dawn"),
+            Some("## Chapter IV: Dawn".to_string())
+        );
+
+        let words = ChapterNumbering {
+            style: NumberStyle::Words,
+            start: 1,
+        };
+        assert_eq!(
+            chapter_header(&ChapterHeaderStyle::Numbered, &words, 4, "Dawn"),
+            Some("## Chapter Four".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_chapter_number_falls_back_to_arabic() {
+        let roman_from_zero = ChapterNumbering {
+            style: NumberStyle::Roman,
+            start: 0,
+        };
+        assert_eq!(format_chapter_number(&roman_from_zero, 1), "0");
+        assert_eq!(format_chapter_number(&roman_from_zero, 2), "I");
+    }
+
+    #[test]
+    fn test_separator_string_values() {
         assert_eq!(
             separator_string(&ChapterSeparator::PageBreak),
             "\n\n---\n\n"
@@ -1162,6 +2006,50 @@ mod tests {
         assert_eq!(result.chapter_count, 3);
     }
 
+    #[test]
+    fn multi_chapter_numbering_start_offset() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["prologue", "ch-1", "ch-2"]);
+        write_chapter(&pp, "prologue", "Prologue", None, "Before.");
+        write_chapter(&pp, "ch-1", "Arrival", None, "During.");
+        write_chapter(&pp, "ch-2", "Departure", None, "After.");
+
+        let mut config = default_config();
+        config.chapter_header_style = ChapterHeaderStyle::NumberedAndTitled;
+        config.chapter_numbering = ChapterNumbering {
+            style: NumberStyle::Arabic,
+            start: 0,
+        };
+
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("## Chapter 0: Prologue"));
+        assert!(result.content.contains("## Chapter 1: Arrival"));
+        assert!(result.content.contains("## Chapter 2: Departure"));
+    }
+
+    #[test]
+    fn multi_chapter_roman_numbering_with_offset() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-a", "ch-b"]);
+        write_chapter(&pp, "ch-a", "Alpha", None, "Body A.");
+        write_chapter(&pp, "ch-b", "Beta", None, "Body B.");
+
+        let mut config = default_config();
+        config.chapter_header_style = ChapterHeaderStyle::Numbered;
+        config.chapter_numbering = ChapterNumbering {
+            style: NumberStyle::Roman,
+            start: 9,
+        };
+
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("## Chapter IX"));
+        assert!(result.content.contains("## Chapter X\n"));
+    }
+
     #[test]
     fn multi_chapter_titled_headers() {
         let dir = setup_test_dir();
@@ -1723,14 +2611,8 @@ mod tests {
         );
 
         let config = CompileConfig {
-            title: "My Novel".to_string(),
-            author: "Jane Author".to_string(),
-            include_title_page: false,
-            chapter_header_style: ChapterHeaderStyle::Titled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1755,10 +2637,8 @@ mod tests {
             author: "A. Writer".to_string(),
             include_title_page: true,
             chapter_header_style: ChapterHeaderStyle::None,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1782,12 +2662,9 @@ mod tests {
         let config = CompileConfig {
             title: "Novel".to_string(),
             author: "Author".to_string(),
-            include_title_page: false,
             chapter_header_style: ChapterHeaderStyle::Numbered,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1806,12 +2683,9 @@ mod tests {
         let config = CompileConfig {
             title: "Novel".to_string(),
             author: "Author".to_string(),
-            include_title_page: false,
             chapter_header_style: ChapterHeaderStyle::NumberedAndTitled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1831,12 +2705,8 @@ mod tests {
         let config = CompileConfig {
             title: "Novel".to_string(),
             author: "Author".to_string(),
-            include_title_page: false,
-            chapter_header_style: ChapterHeaderStyle::Titled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1857,12 +2727,9 @@ mod tests {
         let config = CompileConfig {
             title: "Novel".to_string(),
             author: "Author".to_string(),
-            include_title_page: false,
-            chapter_header_style: ChapterHeaderStyle::Titled,
             chapter_separator: ChapterSeparator::PageBreak,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1882,12 +2749,9 @@ mod tests {
         let config = CompileConfig {
             title: "Novel".to_string(),
             author: "Author".to_string(),
-            include_title_page: false,
-            chapter_header_style: ChapterHeaderStyle::Titled,
             chapter_separator: ChapterSeparator::HorizontalRule,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1912,12 +2776,9 @@ mod tests {
         let config = CompileConfig {
             title: "Novel".to_string(),
             author: "Author".to_string(),
-            include_title_page: false,
-            chapter_header_style: ChapterHeaderStyle::Titled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
             include_synopsis: true,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1937,12 +2798,9 @@ mod tests {
         let config = CompileConfig {
             title: "Novel".to_string(),
             author: "Author".to_string(),
-            include_title_page: false,
-            chapter_header_style: ChapterHeaderStyle::Titled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
             front_matter: "For those who dream.".to_string(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -1961,12 +2819,7 @@ mod tests {
         let md_config = CompileConfig {
             title: "Novel".to_string(),
             author: "Author".to_string(),
-            include_title_page: false,
-            chapter_header_style: ChapterHeaderStyle::Titled,
-            chapter_separator: ChapterSeparator::ThreeStars,
-            output_format: OutputFormat::Markdown,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let pt_config = CompileConfig {
@@ -1994,10 +2847,8 @@ mod tests {
             author: "Author".to_string(),
             include_title_page: true,
             chapter_header_style: ChapterHeaderStyle::NumberedAndTitled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -2018,11 +2869,8 @@ mod tests {
             title: "Title".to_string(),
             author: "Author Name".to_string(),
             include_title_page: true,
-            chapter_header_style: ChapterHeaderStyle::Titled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -2056,10 +2904,10 @@ mod tests {
             author: "A. Writer".to_string(),
             include_title_page: true,
             chapter_header_style: ChapterHeaderStyle::NumberedAndTitled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
             include_synopsis: true,
             front_matter: "For those who dream.".to_string(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -2101,12 +2949,8 @@ mod tests {
         let config = CompileConfig {
             title: "Empty".to_string(),
             author: "Author".to_string(),
-            include_title_page: false,
-            chapter_header_style: ChapterHeaderStyle::Titled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::PlainText,
-            include_synopsis: false,
-            front_matter: String::new(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -2208,6 +3052,79 @@ mod tests {
         assert_eq!(result.chapter_count, 0);
     }
 
+    // ── Page estimates ─────────────────────────────────────────────
+
+    #[test]
+    fn page_estimate_2500_words_at_250_is_10_pages() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1"]);
+        write_chapter(&pp, "ch-1", "A", None, &vec!["word"; 2500].join(" "));
+
+        let mut config = default_config();
+        config.chapter_header_style = ChapterHeaderStyle::None;
+        let result = compile_manuscript(pp, config).unwrap();
+        assert_eq!(result.word_count, 2500);
+        assert_eq!(result.page_estimate, 10);
+    }
+
+    #[test]
+    fn page_estimate_empty_manuscript_is_zero() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let result = compile_manuscript(pp, default_config()).unwrap();
+        assert_eq!(result.page_estimate, 0);
+        assert_eq!(result.manuscript_page_estimate, Some(0));
+    }
+
+    #[test]
+    fn page_estimate_rounds_up_partial_pages() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1"]);
+        write_chapter(&pp, "ch-1", "A", None, &vec!["word"; 2500].join(" "));
+
+        let mut config = default_config();
+        config.chapter_header_style = ChapterHeaderStyle::None;
+        config.words_per_page = 300;
+        let result = compile_manuscript(pp, config).unwrap();
+        assert_eq!(result.page_estimate, 9);
+    }
+
+    #[test]
+    fn page_estimate_zero_words_per_page_is_rejected() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let mut config = default_config();
+        config.words_per_page = 0;
+        let result = compile_manuscript(pp, config);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn manuscript_page_estimate_counts_wrapped_lines() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        // 30 paragraphs of 99 characters wrap to 2 lines each: 60 lines, 3 pages.
+        let paragraph = vec!["word"; 20].join(" ");
+        write_config(&pp, &["ch-1"]);
+        write_chapter(&pp, "ch-1", "A", None, &vec![paragraph; 30].join("\n\n"));
+
+        let mut config = default_config();
+        config.chapter_header_style = ChapterHeaderStyle::None;
+        let result = compile_manuscript(pp.clone(), config.clone()).unwrap();
+        assert_eq!(result.manuscript_page_estimate, Some(3));
+
+        config.body_mode = BodyMode::TitlesOnly;
+        let result = compile_manuscript(pp, config).unwrap();
+        assert_eq!(result.manuscript_page_estimate, None);
+    }
+
     // ── Chapter count matches actual compiled chapters ─────────────
 
     #[test]
@@ -2481,7 +3398,12 @@ mod tests {
     #[test]
     fn test_chapter_header_with_special_chars() {
         assert_eq!(
-            chapter_header(&ChapterHeaderStyle::Titled, 1, "A \"Brave\" & <Bold> Move"),
+            chapter_header(
+                &ChapterHeaderStyle::Titled,
+                &ChapterNumbering::default(),
+                1,
+                "A \"Brave\" & <Bold> Move"
+            ),
             Some("## A \"Brave\" & <Bold> Move".to_string())
         );
     }
@@ -2489,7 +3411,12 @@ mod tests {
     #[test]
     fn test_chapter_header_with_unicode() {
         assert_eq!(
-            chapter_header(&ChapterHeaderStyle::NumberedAndTitled, 7, "第七章"),
+            chapter_header(
+                &ChapterHeaderStyle::NumberedAndTitled,
+                &ChapterNumbering::default(),
+                7,
+                "第七章"
+            ),
             Some("## Chapter 7: 第七章".to_string())
         );
     }
@@ -2498,12 +3425,22 @@ mod tests {
     fn test_chapter_header_with_empty_title() {
         // Even an empty title produces a header for Titled style
         assert_eq!(
-            chapter_header(&ChapterHeaderStyle::Titled, 1, ""),
+            chapter_header(
+                &ChapterHeaderStyle::Titled,
+                &ChapterNumbering::default(),
+                1,
+                ""
+            ),
             Some("## ".to_string())
         );
         // For NumberedAndTitled, it shows "## Chapter 1: "
         assert_eq!(
-            chapter_header(&ChapterHeaderStyle::NumberedAndTitled, 1, ""),
+            chapter_header(
+                &ChapterHeaderStyle::NumberedAndTitled,
+                &ChapterNumbering::default(),
+                1,
+                ""
+            ),
             Some("## Chapter 1: ".to_string())
         );
     }
@@ -2800,10 +3737,10 @@ mod tests {
             author: "A. Writer".to_string(),
             include_title_page: true,
             chapter_header_style: ChapterHeaderStyle::NumberedAndTitled,
-            chapter_separator: ChapterSeparator::ThreeStars,
             output_format: OutputFormat::Html,
             include_synopsis: true,
             front_matter: "For those who dream.".to_string(),
+            ..default_config()
         };
 
         let result = compile_manuscript(pp, config).unwrap();
@@ -2851,7 +3788,7 @@ mod tests {
 
     #[test]
     fn test_render_html_basic() {
-        let html = render_html("# Hello\n\nWorld", "Test Title");
+        let html = render_html("# Hello\n\nWorld", "Test Title", &[]);
         assert!(html.starts_with("<!DOCTYPE html>"));
         assert!(html.contains("<title>Test Title</title>"));
         assert!(html.contains("<h1>Hello</h1>"));
@@ -2862,7 +3799,7 @@ mod tests {
     #[test]
     fn test_render_html_preserves_markdown_features() {
         let md = "**bold** *italic* [link](http://example.com)\n\n- item 1\n- item 2";
-        let html = render_html(md, "Features");
+        let html = render_html(md, "Features", &[]);
         assert!(html.contains("<strong>bold</strong>"));
         assert!(html.contains("<em>italic</em>"));
         assert!(html.contains("<a href=\"http://example.com\">link</a>"));
@@ -2870,6 +3807,252 @@ mod tests {
         assert!(html.contains("<li>item 2</li>"));
     }
 
+    // ── HTML drop caps ─────────────────────────────────────────────
+
+    #[test]
+    fn split_dropcap_is_grapheme_aware() {
+        assert_eq!(split_dropcap("Once upon"), ("O", "nce upon"));
+        assert_eq!(split_dropcap("Élan"), ("É", "lan"));
+        // "E" followed by a combining acute accent is one grapheme
+        assert_eq!(split_dropcap("E\u{301}cole"), ("E\u{301}", "cole"));
+        assert_eq!(
+            split_dropcap("\u{201C}Run,\u{201D} she said"),
+            ("\u{201C}R", "un,\u{201D} she said")
+        );
+        assert_eq!(split_dropcap("\""), ("\"", ""));
+        assert_eq!(split_dropcap(""), ("", ""));
+    }
+
+    #[test]
+    fn dropcaps_wrap_first_paragraph_of_each_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1", "ch-2"]);
+        write_chapter(&pp, "ch-1", "One", None, "Élodie woke.\n\nShe rose.");
+        write_chapter(&pp, "ch-2", "Two", None, "Night fell.");
+
+        let config = CompileConfig {
+            output_format: OutputFormat::Html,
+            dropcaps: true,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result
+            .content
+            .contains(r#"<p><span class="dropcap">É</span>lodie woke.</p>"#));
+        assert!(result.content.contains("<p>She rose.</p>"));
+        assert!(result
+            .content
+            .contains(r#"<p><span class="dropcap">N</span>ight fell.</p>"#));
+        assert!(result.content.contains(".dropcap {"));
+    }
+
+    #[test]
+    fn dropcaps_keep_opening_quote_with_letter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1"]);
+        write_chapter(&pp, "ch-1", "One", None, "\"Wake up,\" she said.");
+
+        let config = CompileConfig {
+            output_format: OutputFormat::Html,
+            dropcaps: true,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result
+            .content
+            .contains("<p><span class=\"dropcap\">\u{201C}W</span>ake up,\u{201D} she said.</p>"));
+    }
+
+    #[test]
+    fn dropcaps_skip_synopsis_paragraph() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1"]);
+        write_chapter(&pp, "ch-1", "One", Some("A summary"), "Body starts.");
+
+        let config = CompileConfig {
+            output_format: OutputFormat::Html,
+            include_synopsis: true,
+            dropcaps: true,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("<p><em>A summary</em></p>"));
+        assert!(result
+            .content
+            .contains(r#"<p><span class="dropcap">B</span>ody starts.</p>"#));
+    }
+
+    #[test]
+    fn dropcaps_skip_scene_headings_inside_a_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1"]);
+        write_chapter(
+            &pp,
+            "ch-1",
+            "One",
+            None,
+            "Opening.\n\n## Scene Two\n\nLater.",
+        );
+
+        let config = CompileConfig {
+            output_format: OutputFormat::Html,
+            dropcaps: true,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result
+            .content
+            .contains(r#"<p><span class="dropcap">O</span>pening.</p>"#));
+        assert!(result.content.contains("<p>Later.</p>"));
+    }
+
+    #[test]
+    fn dropcaps_apply_without_chapter_headers() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1", "ch-2"]);
+        write_chapter(&pp, "ch-1", "One", None, "First.\n\nMore.");
+        write_chapter(&pp, "ch-2", "Two", None, "Second.");
+
+        let config = CompileConfig {
+            output_format: OutputFormat::Html,
+            chapter_header_style: ChapterHeaderStyle::None,
+            dropcaps: true,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result
+            .content
+            .contains(r#"<p><span class="dropcap">F</span>irst.</p>"#));
+        assert!(result.content.contains("<p>More.</p>"));
+        assert!(result
+            .content
+            .contains(r#"<p><span class="dropcap">S</span>econd.</p>"#));
+    }
+
+    #[test]
+    fn dropcaps_off_by_default() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1"]);
+        write_chapter(&pp, "ch-1", "One", None, "Plain start.");
+
+        let config = CompileConfig {
+            output_format: OutputFormat::Html,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("<p>Plain start.</p>"));
+        assert!(!result.content.contains(r#"<span class="dropcap">"#));
+    }
+
+    // ── HTML wiki-link resolution ──────────────────────────────────
+
+    /// Helper: HTML config with wiki-link resolution turned on.
+    fn linked_html_config() -> CompileConfig {
+        CompileConfig {
+            output_format: OutputFormat::Html,
+            resolve_links: true,
+            ..default_config()
+        }
+    }
+
+    #[test]
+    fn resolve_links_turns_in_compile_link_into_anchor() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["arrival", "departure"]);
+        write_chapter(&pp, "arrival", "Arrival", None, "See [[departure]] later.");
+        write_chapter(&pp, "departure", "Departure", None, "Back to [[Arrival]].");
+
+        let result = compile_manuscript(pp, linked_html_config()).unwrap();
+        assert!(result
+            .content
+            .contains(r#"<h2 id="chap-arrival">Arrival</h2>"#));
+        assert!(result
+            .content
+            .contains(r#"<h2 id="chap-departure">Departure</h2>"#));
+        assert!(result
+            .content
+            .contains(r##"See <a href="#chap-departure">Departure</a> later."##));
+        assert!(result
+            .content
+            .contains(r##"Back to <a href="#chap-arrival">Arrival</a>."##));
+    }
+
+    #[test]
+    fn resolve_links_out_of_scope_link_becomes_plain_text() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["arrival"]);
+        write_chapter(
+            &pp,
+            "arrival",
+            "Arrival",
+            None,
+            "Unlike [[epilogue]], this is here.",
+        );
+        write_chapter(&pp, "epilogue", "Epilogue", None, "Not compiled.");
+
+        let result = compile_manuscript(pp, linked_html_config()).unwrap();
+        assert!(result.content.contains("Unlike epilogue, this is here."));
+        assert!(!result.content.contains("[["));
+        assert!(!result.content.contains("chap-epilogue"));
+    }
+
+    #[test]
+    fn resolve_links_disabled_leaves_wiki_links_alone() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["arrival"]);
+        write_chapter(&pp, "arrival", "Arrival", None, "Self [[arrival]].");
+
+        let config = CompileConfig {
+            output_format: OutputFormat::Html,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("[[arrival]]"));
+        assert!(result.content.contains("<h2>Arrival</h2>"));
+    }
+
+    #[test]
+    fn resolve_chapter_links_matches_headers_in_order() {
+        let chapters = [
+            ChapterAnchor {
+                slug: "one".to_string(),
+                title: "Same".to_string(),
+                header: Some("## Same".to_string()),
+                start_line: 0,
+            },
+            ChapterAnchor {
+                slug: "two".to_string(),
+                title: "Same".to_string(),
+                header: Some("## Same".to_string()),
+                start_line: 0,
+            },
+        ];
+        let md = "## Same\n\nA.\n\n## Same\n\nB.";
+        let resolved = resolve_chapter_links(md, &chapters);
+        assert_eq!(
+            resolved,
+            "<h2 id=\"chap-one\">Same</h2>\n\nA.\n\n<h2 id=\"chap-two\">Same</h2>\n\nB."
+        );
+    }
+
     #[test]
     fn test_html_escape_function() {
         assert_eq!(html_escape("Hello"), "Hello");
@@ -2881,4 +4064,350 @@ mod tests {
             "Tom &amp; Jerry &lt;&quot;hi&quot;&gt;"
         );
     }
+
+    // ── render_wiki_links ──────────────────────────────────────────
+
+    /// Helper: write an entity file with just a title and slug.
+    fn write_entity(project_path: &str, entity_type: &str, slug: &str, title: &str) {
+        let dir = PathBuf::from(project_path)
+            .join("entities")
+            .join(entity_type);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(format!("{}.md", slug)),
+            format!("---\ntitle: {}\nslug: {}\n---\n", title, slug),
+        )
+        .unwrap();
+    }
+
+    fn wiki_fixture(pp: &str) {
+        write_config(pp, &["arrival", "departure"]);
+        write_chapter(pp, "arrival", "Arrival", None, "[[alice]] sees [[Nobody]].");
+        write_chapter(pp, "departure", "Departure", None, "Back to [[Arrival]].");
+        write_entity(pp, "character", "alice", "Alice");
+    }
+
+    #[test]
+    fn render_wiki_links_markdown_uses_titles() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        wiki_fixture(&pp);
+
+        let config = CompileConfig {
+            render_wiki_links: true,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("Alice sees Nobody."));
+        assert!(result.content.contains("Back to Arrival."));
+        assert!(!result.content.contains("[["));
+    }
+
+    #[test]
+    fn render_wiki_links_plain_text_uses_titles() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        wiki_fixture(&pp);
+
+        let config = CompileConfig {
+            output_format: OutputFormat::PlainText,
+            render_wiki_links: true,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("Alice sees Nobody."));
+        assert!(!result.content.contains("[["));
+    }
+
+    #[test]
+    fn render_wiki_links_html_anchors_chapters_and_titles_the_rest() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        wiki_fixture(&pp);
+
+        let config = CompileConfig {
+            render_wiki_links: true,
+            ..linked_html_config()
+        };
+        let result = compile_manuscript(pp.clone(), config).unwrap();
+        assert!(result
+            .content
+            .contains(r##"Back to <a href="#chap-arrival">Arrival</a>."##));
+        assert!(result.content.contains("Alice sees Nobody."));
+
+        let config = CompileConfig {
+            output_format: OutputFormat::Html,
+            render_wiki_links: true,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("Back to Arrival."));
+        assert!(!result.content.contains("<a href"));
+    }
+
+    #[test]
+    fn render_wiki_links_disabled_leaves_links_verbatim() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        wiki_fixture(&pp);
+
+        let result = compile_manuscript(pp, default_config()).unwrap();
+        assert!(result.content.contains("[[alice]] sees [[Nobody]]."));
+    }
+
+    #[test]
+    fn render_wiki_links_applies_to_compile_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        wiki_fixture(&pp);
+
+        let config = CompileConfig {
+            render_wiki_links: true,
+            ..default_config()
+        };
+        let result = compile_chapter(pp, "arrival".to_string(), config).unwrap();
+        assert!(result.content.contains("Alice sees Nobody."));
+    }
+
+    // ── LaTeX output ──────────────────────────────────────────────
+
+    #[test]
+    fn test_latex_escape_special_characters() {
+        assert_eq!(latex_escape("plain"), "plain");
+        assert_eq!(latex_escape("Tom & Jerry"), "Tom \\& Jerry");
+        assert_eq!(latex_escape("100%"), "100\\%");
+        assert_eq!(latex_escape("$5 #1 a_b"), "\\$5 \\#1 a\\_b");
+        assert_eq!(latex_escape("{x}"), "\\{x\\}");
+        assert_eq!(latex_escape("~^"), "\\textasciitilde{}\\textasciicircum{}");
+        assert_eq!(latex_escape("a\\b"), "a\\textbackslash{}b");
+    }
+
+    #[test]
+    fn test_render_latex_document_structure() {
+        let latex = render_latex("Hello world.", &default_config(), None);
+        assert!(latex.starts_with("\\documentclass{book}"));
+        assert!(latex.contains("\\title{My Novel}"));
+        assert!(latex.contains("\\author{Jane Author}"));
+        assert!(latex.contains("\\begin{document}"));
+        assert!(latex.contains("Hello world."));
+        assert!(latex.trim_end().ends_with("\\end{document}"));
+    }
+
+    #[test]
+    fn test_render_latex_bold_and_italic() {
+        let latex = render_latex("**bold** and *italic*", &default_config(), None);
+        assert!(latex.contains("\\textbf{bold} and \\emph{italic}"));
+    }
+
+    #[test]
+    fn test_render_latex_titled_header_maps_to_chapter() {
+        let latex = render_latex("## The Beginning\n\nText.", &default_config(), None);
+        assert!(latex.contains("\\chapter{The Beginning}"));
+        assert!(!latex.contains("##"));
+    }
+
+    #[test]
+    fn test_render_latex_numbered_header_uses_starred_chapter() {
+        let mut config = default_config();
+        config.chapter_header_style = ChapterHeaderStyle::NumberedAndTitled;
+        let latex = render_latex("## Chapter 1: The Beginning\n\nText.", &config, None);
+        assert!(latex.contains("\\chapter*{Chapter 1: The Beginning}"));
+    }
+
+    #[test]
+    fn test_render_latex_three_stars_separator() {
+        let latex = render_latex("One.\n\n* * *\n\nTwo.", &default_config(), None);
+        assert!(latex.contains("\\bigskip\n\\begin{center}*\\quad*\\quad*\\end{center}"));
+    }
+
+    #[test]
+    fn test_render_latex_page_break_separator() {
+        let mut config = default_config();
+        config.chapter_separator = ChapterSeparator::PageBreak;
+        let latex = render_latex("One.\n\n---\n\nTwo.", &config, None);
+        assert!(latex.contains("\\clearpage"));
+    }
+
+    #[test]
+    fn latex_output_escapes_special_characters_in_titles_and_body() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1"]);
+        write_chapter(
+            &pp,
+            "ch-1",
+            "Profit & Loss",
+            None,
+            "It cost $5, a 10% tip, and item #3_b {really}.",
+        );
+
+        let mut config = default_config();
+        config.title = "R&D_Notes".to_string();
+        config.output_format = OutputFormat::Latex;
+
+        let result = compile_manuscript(pp, config).unwrap();
+        assert_eq!(result.format, OutputFormat::Latex);
+        assert!(result.content.contains("\\title{R\\&D\\_Notes}"));
+        assert!(result.content.contains("\\chapter{Profit \\& Loss}"));
+        assert!(result
+            .content
+            .contains("It cost \\$5, a 10\\% tip, and item \\#3\\_b \\{really\\}."));
+    }
+
+    #[test]
+    fn latex_output_maps_each_chapter_header() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1", "ch-2"]);
+        write_chapter(&pp, "ch-1", "One", None, "First.");
+        write_chapter(&pp, "ch-2", "Two", None, "Second.");
+
+        let mut config = default_config();
+        config.output_format = OutputFormat::Latex;
+
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("\\chapter{One}"));
+        assert!(result.content.contains("\\chapter{Two}"));
+        assert_eq!(result.content.matches("\\begin{center}").count(), 1);
+        assert_eq!(result.chapter_count, 2);
+    }
+
+    #[test]
+    fn latex_output_title_page_becomes_maketitle() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1"]);
+        write_chapter(&pp, "ch-1", "One", None, "First.");
+
+        let mut config = default_config();
+        config.include_title_page = true;
+        config.output_format = OutputFormat::Latex;
+
+        let result = compile_manuscript(pp, config).unwrap();
+        assert!(result.content.contains("\\maketitle"));
+        assert!(!result.content.contains("\\part*{My Novel}"));
+        assert!(!result.content.contains("\\textbf{Jane Author}"));
+    }
+
+    #[test]
+    fn latex_output_title_page_with_empty_author_keeps_first_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &["ch-1"]);
+        write_chapter(&pp, "ch-1", "One", None, "First.");
+
+        let config = CompileConfig {
+            author: String::new(),
+            include_title_page: true,
+            output_format: OutputFormat::Latex,
+            ..default_config()
+        };
+        let result = compile_manuscript(pp, config).unwrap();
+        assert_eq!(result.content.matches("\\maketitle").count(), 1);
+        assert!(result.content.contains("\\chapter{One}\n\nFirst."));
+    }
+
+    #[test]
+    fn test_render_latex_formats_emphasis_in_headings() {
+        let latex = render_latex("## The *Long* **Night**\n\nText.", &default_config(), None);
+        assert!(latex.contains("\\chapter{The \\emph{Long} \\textbf{Night}}\n\nText."));
+    }
+
+    // ── compile_chapter ─────────────────────────────────────────────
+
+    #[test]
+    fn compile_chapter_markdown() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_chapter(&pp, "ch-1", "Beginning", Some("Intro"), "First body.\n");
+        write_chapter(&pp, "ch-2", "Middle", Some("Turn"), "Second body.\n");
+        write_config(&pp, &["ch-1", "ch-2"]);
+
+        let config = CompileConfig {
+            include_title_page: true,
+            include_synopsis: true,
+            front_matter: "Dedication".to_string(),
+            ..default_config()
+        };
+        let result = compile_chapter(pp, "ch-2".to_string(), config).unwrap();
+
+        assert_eq!(result.content, "## Middle\n\n*Turn*\n\nSecond body.");
+        assert_eq!(result.chapter_count, 1);
+        assert_eq!(result.format, OutputFormat::Markdown);
+    }
+
+    #[test]
+    fn compile_chapter_numbered_uses_manuscript_position() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_chapter(&pp, "ch-1", "Beginning", None, "One.\n");
+        write_chapter(&pp, "ch-2", "Middle", None, "Two.\n");
+        write_config(&pp, &["ch-1", "ch-2"]);
+
+        let config = CompileConfig {
+            chapter_header_style: ChapterHeaderStyle::Numbered,
+            ..default_config()
+        };
+        let result = compile_chapter(pp, "ch-2".to_string(), config).unwrap();
+
+        assert!(result.content.starts_with("## Chapter 2\n"));
+    }
+
+    #[test]
+    fn compile_chapter_html() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_chapter(&pp, "ch-1", "Beginning", None, "Some **bold** text.\n");
+        write_config(&pp, &["ch-1"]);
+
+        let config = CompileConfig {
+            output_format: OutputFormat::Html,
+            ..default_config()
+        };
+        let result = compile_chapter(pp, "ch-1".to_string(), config).unwrap();
+
+        assert_eq!(result.format, OutputFormat::Html);
+        assert!(result.content.contains("<h2>Beginning</h2>"));
+        assert!(result.content.contains("<strong>bold</strong>"));
+        assert!(!result.content.contains("<hr"));
+    }
+
+    #[test]
+    fn compile_chapter_plain_text() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_chapter(&pp, "ch-1", "Beginning", None, "Some **bold** text.\n");
+        write_config(&pp, &["ch-1"]);
+
+        let config = CompileConfig {
+            output_format: OutputFormat::PlainText,
+            ..default_config()
+        };
+        let result = compile_chapter(pp, "ch-1".to_string(), config).unwrap();
+
+        assert_eq!(result.format, OutputFormat::PlainText);
+        assert!(result.content.contains("BEGINNING"));
+        assert!(result.content.contains("Some bold text."));
+        assert!(!result.content.contains("**"));
+        assert!(!result.content.contains("##"));
+    }
+
+    #[test]
+    fn compile_chapter_missing_slug_returns_not_found() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_config(&pp, &[]);
+
+        let result = compile_chapter(pp, "ghost".to_string(), default_config());
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
 }
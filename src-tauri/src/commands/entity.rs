@@ -1,13 +1,15 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::commands::search::refresh_backlink_index;
 use crate::error::AppError;
 use crate::models::entity::{
-    EntityField, EntityFrontmatter, EntityInstance, EntitySchema, EntitySummary, FieldType,
-    SchemaSummary, SpiderAxis,
+    AxisComparison, ComparedEntity, CsvImportReport, CsvRowError, EntityField, EntityFrontmatter,
+    EntityInstance, EntitySchema, EntitySummary, EntityTemplate, FieldType, SaveEntityResult,
+    SchemaSummary, SpiderAxis, SpiderComparison, TagCount, ValidationError,
 };
 use crate::services::frontmatter;
-use crate::services::slug_service::slugify;
+use crate::services::slug_service::{slugify, unique_slug};
 use crate::services::yaml_service::{read_yaml, write_yaml};
 
 /// List all entity schemas in the project's schemas/ directory.
@@ -165,20 +167,22 @@ pub fn create_entity(
     schema_type: String,
     title: String,
 ) -> Result<EntityInstance, AppError> {
-    let slug = slugify(&title);
+    let existing = list_entities(project_path.clone(), schema_type.clone())?;
+    if let Some(same) = existing.iter().find(|e| e.title == title) {
+        return Err(AppError::AlreadyExists(format!(
+            "Entity already exists: {}/{}",
+            schema_type, same.slug
+        )));
+    }
+
     let entities_dir = PathBuf::from(&project_path)
         .join("entities")
         .join(&schema_type);
 
     std::fs::create_dir_all(&entities_dir)?;
 
+    let slug = unique_slug(&entity_slugs(&entities_dir)?, &slugify(&title));
     let entity_path = entities_dir.join(format!("{}.md", slug));
-    if entity_path.exists() {
-        return Err(AppError::AlreadyExists(format!(
-            "Entity already exists: {}/{}",
-            schema_type, slug
-        )));
-    }
 
     let fm = EntityFrontmatter {
         title: title.clone(),
@@ -203,10 +207,189 @@ pub fn create_entity(
     })
 }
 
+/// Whether a field value counts as "not filled in".
+fn is_blank(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => true,
+        serde_json::Value::String(s) => s.trim().is_empty(),
+        serde_json::Value::Array(a) => a.is_empty(),
+        _ => false,
+    }
+}
+
+/// Check an entity's fields against its schema.
+///
+/// Required fields must be non-empty, `Number` fields must be numbers within
+/// `min`/`max`, and `Select` fields must hold one of the declared options.
+/// Returns every violation found rather than stopping at the first.
+pub fn validate_entity(
+    schema: &EntitySchema,
+    entity: &EntityInstance,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    let mut fail = |field: &EntityField, message: String| {
+        errors.push(ValidationError {
+            field: field.name.clone(),
+            message,
+        })
+    };
+
+    for field in &schema.fields {
+        let value = match entity.fields.get(&field.name) {
+            Some(v) if !is_blank(v) => v,
+            _ => {
+                if field.required {
+                    fail(field, format!("{} is required", field.label));
+                }
+                continue;
+            }
+        };
+
+        match &field.field_type {
+            FieldType::Number => match value.as_f64() {
+                None => fail(field, format!("{} must be a number", field.label)),
+                Some(n) => {
+                    if let Some(min) = field.min.filter(|min| n < *min) {
+                        fail(field, format!("{} must be at least {}", field.label, min));
+                    }
+                    if let Some(max) = field.max.filter(|max| n > *max) {
+                        fail(field, format!("{} must be at most {}", field.label, max));
+                    }
+                }
+            },
+            FieldType::Select => {
+                if let Some(options) = &field.options {
+                    let valid = value
+                        .as_str()
+                        .is_some_and(|v| options.iter().any(|o| o == v));
+                    if !valid {
+                        fail(
+                            field,
+                            format!("{} must be one of: {}", field.label, options.join(", ")),
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Clamp each spider value to its schema axis range, dropping values for axes
+/// the schema doesn't define. Returns a warning per dropped value.
+///
+/// NaN and infinite values are rejected outright rather than clamped.
+fn normalize_spider_values(
+    schema: &EntitySchema,
+    entity: &mut EntityInstance,
+) -> Result<Vec<String>, AppError> {
+    let mut axes: Vec<String> = entity.spider_values.keys().cloned().collect();
+    axes.sort();
+
+    let mut warnings = Vec::new();
+    for axis_name in axes {
+        let value = entity.spider_values[&axis_name];
+        if !value.is_finite() {
+            return Err(AppError::Validation(format!(
+                "Spider value for '{}' must be a finite number, got {}",
+                axis_name, value
+            )));
+        }
+        match schema.spider_axes.iter().find(|a| a.name == axis_name) {
+            Some(axis) => {
+                entity
+                    .spider_values
+                    .insert(axis_name, value.clamp(axis.min, axis.max));
+            }
+            None => {
+                entity.spider_values.remove(&axis_name);
+                warnings.push(format!(
+                    "Dropped spider value for unknown axis '{}'",
+                    axis_name
+                ));
+            }
+        }
+    }
+    Ok(warnings)
+}
+
+/// Check an entity against its schema, when one exists: normalize its spider
+/// values and validate its fields. Returns the spider warnings and the field
+/// violations; schemaless types have neither.
+fn check_entity(
+    project_path: &str,
+    entity: &mut EntityInstance,
+) -> Result<(Vec<String>, Vec<ValidationError>), AppError> {
+    match get_schema(project_path.to_string(), entity.schema_slug.clone()) {
+        Ok(schema) => {
+            let warnings = normalize_spider_values(&schema, entity)?;
+            let errors = validate_entity(&schema, entity).err().unwrap_or_default();
+            Ok((warnings, errors))
+        }
+        Err(AppError::NotFound(_)) => Ok((Vec::new(), Vec::new())),
+        Err(e) => Err(e),
+    }
+}
+
 /// Save (update) an existing entity instance.
+///
+/// When the schema exists, spider values are clamped to their axis ranges
+/// (values for unknown axes are dropped with a warning) and fields are
+/// validated; any field violation is a Validation error listing all of them,
+/// and nothing is written. Returns the entity as written.
 #[tauri::command]
-pub fn save_entity(project_path: String, entity: EntityInstance) -> Result<(), AppError> {
-    let entities_dir = PathBuf::from(&project_path)
+pub fn save_entity(
+    project_path: String,
+    mut entity: EntityInstance,
+) -> Result<SaveEntityResult, AppError> {
+    let (warnings, errors) = check_entity(&project_path, &mut entity)?;
+    if !errors.is_empty() {
+        let details: Vec<String> = errors
+            .iter()
+            .map(|e| format!("{}: {}", e.field, e.message))
+            .collect();
+        return Err(AppError::Validation(format!(
+            "Entity {}/{} is invalid: {}",
+            entity.schema_slug,
+            entity.slug,
+            details.join("; ")
+        )));
+    }
+
+    write_entity(&project_path, entity.clone())?;
+    Ok(SaveEntityResult { entity, warnings })
+}
+
+/// Save an entity that may still be incomplete, e.g. from editor autosave.
+///
+/// Like `save_entity`, but field violations don't block the write; each one
+/// is returned as a warning instead. Non-finite spider values are still
+/// rejected.
+#[tauri::command]
+pub fn save_entity_draft(
+    project_path: String,
+    mut entity: EntityInstance,
+) -> Result<SaveEntityResult, AppError> {
+    let (mut warnings, errors) = check_entity(&project_path, &mut entity)?;
+    warnings.extend(
+        errors
+            .into_iter()
+            .map(|e| format!("{}: {}", e.field, e.message)),
+    );
+
+    write_entity(&project_path, entity.clone())?;
+    Ok(SaveEntityResult { entity, warnings })
+}
+
+/// Write an entity's Markdown file without validating it.
+fn write_entity(project_path: &str, entity: EntityInstance) -> Result<(), AppError> {
+    let entities_dir = PathBuf::from(project_path)
         .join("entities")
         .join(&entity.schema_slug);
 
@@ -225,6 +408,7 @@ pub fn save_entity(project_path: String, entity: EntityInstance) -> Result<(), A
 
     let content = frontmatter::serialize(&fm, &entity.body)?;
     std::fs::write(&entity_path, content)?;
+    refresh_backlink_index(&PathBuf::from(project_path), &entity_path);
     Ok(())
 }
 
@@ -252,6 +436,9 @@ pub fn delete_entity(
 }
 
 /// Rename an entity instance (update title and potentially slug/filename).
+///
+/// A slug already used by another entity of the same type gets a numeric
+/// suffix rather than overwriting that entity.
 #[tauri::command]
 pub fn rename_entity(
     project_path: String,
@@ -260,7 +447,12 @@ pub fn rename_entity(
     new_title: String,
 ) -> Result<EntityInstance, AppError> {
     let mut entity = get_entity(project_path.clone(), schema_type.clone(), old_slug.clone())?;
-    let new_slug = slugify(&new_title);
+    let entities_dir = PathBuf::from(&project_path)
+        .join("entities")
+        .join(&schema_type);
+    let mut siblings = entity_slugs(&entities_dir)?;
+    siblings.retain(|slug| *slug != old_slug);
+    let new_slug = unique_slug(&siblings, &slugify(&new_title));
 
     entity.title = new_title;
 
@@ -278,6 +470,482 @@ pub fn rename_entity(
     Ok(entity)
 }
 
+// ── Tags ────────────────────────────────────────────────────────
+
+/// Summaries of every entity in every schema directory under entities/.
+pub(crate) fn list_all_entities(project_path: &str) -> Result<Vec<EntitySummary>, AppError> {
+    let entities_dir = PathBuf::from(project_path).join("entities");
+    if !entities_dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut summaries = Vec::new();
+    for entry in std::fs::read_dir(&entities_dir)? {
+        let entry = entry?;
+        if !entry.path().is_dir() {
+            continue;
+        }
+        let schema_type = entry.file_name().to_string_lossy().to_string();
+        summaries.extend(list_entities(project_path.to_string(), schema_type)?);
+    }
+
+    summaries.sort_by(|a, b| {
+        a.schema_type
+            .cmp(&b.schema_type)
+            .then_with(|| a.title.cmp(&b.title))
+    });
+    Ok(summaries)
+}
+
+/// List entities of any schema type that carry `tag` (exact, case-sensitive match).
+#[tauri::command]
+pub fn list_entities_by_tag(
+    project_path: String,
+    tag: String,
+) -> Result<Vec<EntitySummary>, AppError> {
+    Ok(list_all_entities(&project_path)?
+        .into_iter()
+        .filter(|e| e.tags.contains(&tag))
+        .collect())
+}
+
+/// List every tag used across all entities with its usage count, most used first.
+#[tauri::command]
+pub fn list_all_tags(project_path: String) -> Result<Vec<TagCount>, AppError> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for entity in list_all_entities(&project_path)? {
+        for tag in entity.tags {
+            *counts.entry(tag).or_insert(0) += 1;
+        }
+    }
+
+    let mut tags: Vec<TagCount> = counts
+        .into_iter()
+        .map(|(tag, count)| TagCount { tag, count })
+        .collect();
+    tags.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+    Ok(tags)
+}
+
+// ── Entity References ───────────────────────────────────────────
+
+/// Load every schema in the project's schemas/ directory.
+fn load_all_schemas(project_path: &str) -> Result<Vec<EntitySchema>, AppError> {
+    let summaries = list_schemas(project_path.to_string())?;
+    summaries
+        .into_iter()
+        .map(|s| get_schema(project_path.to_string(), s.entity_type))
+        .collect()
+}
+
+/// Follow a reference field on an entity to the entity it points at.
+///
+/// Returns `None` when the field is unset or the target entity no longer exists
+/// (a dangling reference). Errors if the field is not a reference field.
+#[tauri::command]
+pub fn resolve_entity_reference(
+    project_path: String,
+    schema_type: String,
+    slug: String,
+    field: String,
+) -> Result<Option<EntityInstance>, AppError> {
+    let schema = get_schema(project_path.clone(), schema_type.clone())?;
+    let target_schema = match schema.fields.iter().find(|f| f.name == field) {
+        Some(EntityField {
+            field_type: FieldType::Reference,
+            reference_schema: Some(schema),
+            ..
+        }) => schema.clone(),
+        Some(_) => {
+            return Err(AppError::Validation(format!(
+                "Field is not a reference with a target schema: {}.{}",
+                schema_type, field
+            )))
+        }
+        None => {
+            return Err(AppError::NotFound(format!(
+                "Field not found: {}.{}",
+                schema_type, field
+            )))
+        }
+    };
+
+    let entity = get_entity(project_path.clone(), schema_type, slug)?;
+    let target_slug = match entity.fields.get(&field).and_then(|v| v.as_str()) {
+        Some(s) if !s.is_empty() => s.to_string(),
+        _ => return Ok(None),
+    };
+
+    match get_entity(project_path, target_schema, target_slug) {
+        Ok(target) => Ok(Some(target)),
+        Err(AppError::NotFound(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Find all entities whose reference fields point at the given entity.
+#[tauri::command]
+pub fn find_referencing_entities(
+    project_path: String,
+    schema_type: String,
+    slug: String,
+) -> Result<Vec<EntitySummary>, AppError> {
+    let mut results = Vec::new();
+
+    for schema in load_all_schemas(&project_path)? {
+        let reference_fields: Vec<&str> = schema
+            .fields
+            .iter()
+            .filter(|f| {
+                f.field_type == FieldType::Reference
+                    && f.reference_schema.as_deref() == Some(schema_type.as_str())
+            })
+            .map(|f| f.name.as_str())
+            .collect();
+        if reference_fields.is_empty() {
+            continue;
+        }
+
+        for summary in list_entities(project_path.clone(), schema.entity_type.clone())? {
+            let entity = get_entity(
+                project_path.clone(),
+                schema.entity_type.clone(),
+                summary.slug.clone(),
+            )?;
+            let points_here = reference_fields
+                .iter()
+                .any(|name| entity.fields.get(*name).and_then(|v| v.as_str()) == Some(&slug));
+            if points_here {
+                results.push(summary);
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+// ── Spider Comparison ───────────────────────────────────────────
+
+/// Collect every schema axis's value for several entities of the same type.
+///
+/// Entities missing a value for an axis get the axis default. Entity order in
+/// the result follows `slugs`.
+#[tauri::command]
+pub fn compare_spider_values(
+    project_path: String,
+    schema_type: String,
+    slugs: Vec<String>,
+) -> Result<SpiderComparison, AppError> {
+    let schema = get_schema(project_path.clone(), schema_type.clone())?;
+    let entities = slugs
+        .into_iter()
+        .map(|slug| get_entity(project_path.clone(), schema_type.clone(), slug))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let axes = schema
+        .spider_axes
+        .iter()
+        .map(|axis| AxisComparison {
+            name: axis.name.clone(),
+            min: axis.min,
+            max: axis.max,
+            values: entities
+                .iter()
+                .map(|e| {
+                    e.spider_values
+                        .get(&axis.name)
+                        .copied()
+                        .unwrap_or(axis.default)
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(SpiderComparison {
+        schema_type,
+        entities: entities
+            .into_iter()
+            .map(|e| ComparedEntity {
+                slug: e.slug,
+                title: e.title,
+            })
+            .collect(),
+        axes,
+    })
+}
+
+// ── Entity Templates ────────────────────────────────────────────
+
+fn template_path(project_path: &str, schema_type: &str, name: &str) -> PathBuf {
+    PathBuf::from(project_path)
+        .join("templates")
+        .join(schema_type)
+        .join(format!("{}.yaml", slugify(name)))
+}
+
+/// Save (create or overwrite) a named template of field values for a schema type.
+#[tauri::command]
+pub fn save_entity_template(
+    project_path: String,
+    schema_type: String,
+    name: String,
+    fields: HashMap<String, serde_json::Value>,
+) -> Result<EntityTemplate, AppError> {
+    let template = EntityTemplate {
+        name: name.clone(),
+        schema_type: schema_type.clone(),
+        fields,
+    };
+    write_yaml(
+        &template_path(&project_path, &schema_type, &name),
+        &template,
+    )?;
+    Ok(template)
+}
+
+/// List all templates saved for a schema type, sorted by name.
+#[tauri::command]
+pub fn list_entity_templates(
+    project_path: String,
+    schema_type: String,
+) -> Result<Vec<EntityTemplate>, AppError> {
+    let templates_dir = PathBuf::from(&project_path)
+        .join("templates")
+        .join(&schema_type);
+
+    if !templates_dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut templates = Vec::new();
+    for entry in std::fs::read_dir(&templates_dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) == Some("yaml") {
+            let template: EntityTemplate = read_yaml(&path)?;
+            templates.push(template);
+        }
+    }
+
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(templates)
+}
+
+/// Create a new entity pre-filled with a template's field values and the
+/// schema's spider-axis defaults.
+#[tauri::command]
+pub fn create_entity_from_template(
+    project_path: String,
+    schema_type: String,
+    title: String,
+    template_name: String,
+) -> Result<EntityInstance, AppError> {
+    let path = template_path(&project_path, &schema_type, &template_name);
+    if !path.exists() {
+        return Err(AppError::NotFound(format!(
+            "Template not found: {}/{}",
+            schema_type, template_name
+        )));
+    }
+    let template: EntityTemplate = read_yaml(&path)?;
+    let schema = get_schema(project_path.clone(), schema_type.clone())?;
+
+    let mut entity = create_entity(project_path.clone(), schema_type, title)?;
+    entity.fields = template.fields;
+    entity.spider_values = schema
+        .spider_axes
+        .iter()
+        .map(|axis| (axis.name.clone(), axis.default))
+        .collect();
+
+    // Templates may be partial, so the new entity is written without validation,
+    // just like a blank `create_entity`.
+    write_entity(&project_path, entity.clone())?;
+    Ok(entity)
+}
+
+// ── CSV Import ──────────────────────────────────────────────────
+
+/// Convert a CSV cell into a JSON value suited to the field's type.
+/// Unparseable numbers stay strings so validation can report them.
+fn csv_cell_value(field_type: &FieldType, cell: &str) -> serde_json::Value {
+    match field_type {
+        FieldType::Number => cell
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(serde_json::Value::Number)
+            .unwrap_or_else(|| serde_json::Value::String(cell.to_string())),
+        FieldType::Boolean => match cell.to_lowercase().as_str() {
+            "true" | "yes" | "1" => serde_json::Value::Bool(true),
+            "false" | "no" | "0" => serde_json::Value::Bool(false),
+            _ => serde_json::Value::String(cell.to_string()),
+        },
+        _ => serde_json::Value::String(cell.to_string()),
+    }
+}
+
+/// Slugs of the entity files in a schema type's directory.
+fn entity_slugs(entities_dir: &std::path::Path) -> Result<Vec<String>, AppError> {
+    if !entities_dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut slugs = Vec::new();
+    for entry in std::fs::read_dir(entities_dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) == Some("md") {
+            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                slugs.push(stem.to_string());
+            }
+        }
+    }
+    Ok(slugs)
+}
+
+/// Create entities in bulk from CSV text.
+///
+/// The header row names schema fields; a `title` column is required and an
+/// optional `tags` column holds `;`-separated tags. Each data row becomes one
+/// entity. Rows that fail schema validation or have the wrong number of cells
+/// are skipped and reported, while the remaining rows are still imported.
+/// Titles that slugify to an existing slug get a numeric suffix.
+#[tauri::command]
+pub fn import_entities_csv(
+    project_path: String,
+    schema_type: String,
+    csv: String,
+) -> Result<CsvImportReport, AppError> {
+    let schema = get_schema(project_path.clone(), schema_type.clone())?;
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(csv.as_bytes());
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| AppError::Validation(format!("Invalid CSV header: {}", e)))?
+        .iter()
+        .map(str::to_string)
+        .collect();
+
+    let title_col = headers
+        .iter()
+        .position(|h| h == "title")
+        .ok_or_else(|| AppError::Validation("CSV has no 'title' column".to_string()))?;
+    let tags_col = headers.iter().position(|h| h == "tags");
+
+    let mut columns: Vec<(usize, &EntityField)> = Vec::new();
+    for (i, header) in headers.iter().enumerate() {
+        if i == title_col || Some(i) == tags_col {
+            continue;
+        }
+        let field = schema
+            .fields
+            .iter()
+            .find(|f| &f.name == header)
+            .ok_or_else(|| {
+                AppError::Validation(format!(
+                    "CSV column '{}' is not a field of {}",
+                    header, schema_type
+                ))
+            })?;
+        columns.push((i, field));
+    }
+
+    let entities_dir = PathBuf::from(&project_path)
+        .join("entities")
+        .join(&schema_type);
+    let mut existing_slugs = entity_slugs(&entities_dir)?;
+    let mut report = CsvImportReport {
+        imported: Vec::new(),
+        failed: Vec::new(),
+    };
+
+    for (index, record) in reader.records().enumerate() {
+        // Records can span lines when a quoted cell holds newlines, so prefer
+        // the reader's own position over counting rows.
+        let fallback_line = index + 2;
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                report.failed.push(CsvRowError {
+                    line: e.position().map_or(fallback_line, |p| p.line() as usize),
+                    title: String::new(),
+                    errors: vec![ValidationError {
+                        field: "row".to_string(),
+                        message: e.to_string(),
+                    }],
+                });
+                continue;
+            }
+        };
+        let line = record
+            .position()
+            .map_or(fallback_line, |p| p.line() as usize);
+        let title = record.get(title_col).unwrap_or("").to_string();
+
+        if title.is_empty() {
+            report.failed.push(CsvRowError {
+                line,
+                title,
+                errors: vec![ValidationError {
+                    field: "title".to_string(),
+                    message: "Title is required".to_string(),
+                }],
+            });
+            continue;
+        }
+
+        let fields: HashMap<String, serde_json::Value> = columns
+            .iter()
+            .filter_map(|(i, field)| {
+                let cell = record.get(*i).filter(|c| !c.is_empty())?;
+                Some((field.name.clone(), csv_cell_value(&field.field_type, cell)))
+            })
+            .collect();
+        let tags: Vec<String> = tags_col
+            .and_then(|i| record.get(i))
+            .map(|cell| {
+                cell.split(';')
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let entity = EntityInstance {
+            slug: unique_slug(&existing_slugs, &slugify(&title)),
+            title,
+            schema_slug: schema_type.clone(),
+            tags,
+            spider_values: HashMap::new(),
+            fields,
+            body: String::new(),
+        };
+
+        if let Err(errors) = validate_entity(&schema, &entity) {
+            report.failed.push(CsvRowError {
+                line,
+                title: entity.title,
+                errors,
+            });
+            continue;
+        }
+
+        let summary = EntitySummary {
+            title: entity.title.clone(),
+            slug: entity.slug.clone(),
+            schema_type: schema_type.clone(),
+            tags: entity.tags.clone(),
+        };
+        save_entity(project_path.clone(), entity)?;
+        existing_slugs.push(summary.slug.clone());
+        report.imported.push(summary);
+    }
+
+    Ok(report)
+}
+
 // ── Default Schemas ─────────────────────────────────────────────
 
 /// Returns the 4 rich default entity schemas for new projects.
@@ -310,6 +978,7 @@ fn character_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "age".to_string(),
@@ -321,6 +990,7 @@ fn character_schema() -> EntitySchema {
                 options: None,
                 min: Some(0.0),
                 max: Some(200.0),
+                reference_schema: None,
             },
             EntityField {
                 name: "occupation".to_string(),
@@ -332,6 +1002,7 @@ fn character_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "personality".to_string(),
@@ -345,6 +1016,7 @@ fn character_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "backstory".to_string(),
@@ -358,6 +1030,7 @@ fn character_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "arc".to_string(),
@@ -373,6 +1046,7 @@ fn character_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
         ],
         spider_axes: vec![
@@ -462,6 +1136,7 @@ fn place_schema() -> EntitySchema {
                 ]),
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "era".to_string(),
@@ -473,6 +1148,7 @@ fn place_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "atmosphere".to_string(),
@@ -488,6 +1164,7 @@ fn place_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "significance".to_string(),
@@ -499,6 +1176,7 @@ fn place_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "description".to_string(),
@@ -510,6 +1188,7 @@ fn place_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
         ],
         spider_axes: vec![
@@ -601,6 +1280,7 @@ fn item_schema() -> EntitySchema {
                 ]),
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "owner".to_string(),
@@ -612,6 +1292,7 @@ fn item_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "origin".to_string(),
@@ -623,6 +1304,7 @@ fn item_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "significance".to_string(),
@@ -634,6 +1316,7 @@ fn item_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "description".to_string(),
@@ -645,6 +1328,7 @@ fn item_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
         ],
         spider_axes: vec![
@@ -726,6 +1410,7 @@ fn idea_schema() -> EntitySchema {
                 ]),
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "status".to_string(),
@@ -742,6 +1427,7 @@ fn idea_schema() -> EntitySchema {
                 ]),
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "related_themes".to_string(),
@@ -755,6 +1441,7 @@ fn idea_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
             EntityField {
                 name: "description".to_string(),
@@ -768,6 +1455,7 @@ fn idea_schema() -> EntitySchema {
                 options: None,
                 min: None,
                 max: None,
+                reference_schema: None,
             },
         ],
         spider_axes: vec![
@@ -1316,6 +2004,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_entity_colliding_titles_get_distinct_slugs() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let cafe = create_entity(pp.clone(), "place".to_string(), "Cafe".to_string()).unwrap();
+        let accented = create_entity(pp.clone(), "place".to_string(), "Café".to_string()).unwrap();
+
+        assert_eq!(cafe.slug, "cafe");
+        assert_eq!(accented.slug, "cafe-2");
+        let first = get_entity(pp.clone(), "place".to_string(), "cafe".to_string()).unwrap();
+        let second = get_entity(pp, "place".to_string(), "cafe-2".to_string()).unwrap();
+        assert_eq!(first.title, "Cafe");
+        assert_eq!(second.title, "Café");
+    }
+
     #[test]
     fn create_entity_special_characters_in_title_get_slugified() {
         let dir = setup_test_dir();
@@ -1487,67 +2191,641 @@ mod tests {
         assert_eq!(loaded.spider_values.get("Rarity"), Some(&9.0));
     }
 
-    // ── delete_entity ───────────────────────────────────────────────
+    // ── validate_entity ─────────────────────────────────────────────
+
+    /// Helper: a schema with one required text, one bounded number and one select field.
+    fn validation_schema() -> EntitySchema {
+        let field = |name: &str, field_type: FieldType, required: bool| EntityField {
+            name: name.to_string(),
+            label: name.to_string(),
+            field_type,
+            required,
+            placeholder: None,
+            description: None,
+            options: None,
+            min: None,
+            max: None,
+            reference_schema: None,
+        };
+        EntitySchema {
+            name: "Creature".to_string(),
+            entity_type: "creature".to_string(),
+            icon: None,
+            color: None,
+            description: None,
+            fields: vec![
+                field("name", FieldType::ShortText, true),
+                EntityField {
+                    min: Some(0.0),
+                    max: Some(100.0),
+                    reference_schema: None,
+                    ..field("age", FieldType::Number, false)
+                },
+                EntityField {
+                    options: Some(vec!["land".to_string(), "sea".to_string()]),
+                    ..field("habitat", FieldType::Select, false)
+                },
+            ],
+            spider_axes: vec![],
+        }
+    }
+
+    fn creature(fields: &[(&str, serde_json::Value)]) -> EntityInstance {
+        EntityInstance {
+            title: "Kraken".to_string(),
+            slug: "kraken".to_string(),
+            schema_slug: "creature".to_string(),
+            tags: vec![],
+            spider_values: HashMap::new(),
+            fields: fields
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect(),
+            body: String::new(),
+        }
+    }
 
     #[test]
-    fn delete_entity_removes_file() {
+    fn validate_entity_accepts_valid_entity() {
+        let entity = creature(&[
+            ("name", serde_json::json!("Kraken")),
+            ("age", serde_json::json!(42)),
+            ("habitat", serde_json::json!("sea")),
+        ]);
+        assert!(validate_entity(&validation_schema(), &entity).is_ok());
+    }
+
+    #[test]
+    fn validate_entity_reports_missing_required_field() {
+        let entity = creature(&[("name", serde_json::json!("  "))]);
+        let errors = validate_entity(&validation_schema(), &entity).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "name");
+        assert!(errors[0].message.contains("required"));
+    }
+
+    #[test]
+    fn validate_entity_reports_out_of_range_number() {
+        let entity = creature(&[
+            ("name", serde_json::json!("Kraken")),
+            ("age", serde_json::json!(250)),
+        ]);
+        let errors = validate_entity(&validation_schema(), &entity).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "age");
+        assert!(errors[0].message.contains("at most 100"));
+    }
+
+    #[test]
+    fn validate_entity_reports_invalid_select_option() {
+        let entity = creature(&[
+            ("name", serde_json::json!("Kraken")),
+            ("habitat", serde_json::json!("sky")),
+        ]);
+        let errors = validate_entity(&validation_schema(), &entity).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "habitat");
+    }
+
+    #[test]
+    fn validate_entity_collects_all_violations() {
+        let entity = creature(&[
+            ("age", serde_json::json!(-1)),
+            ("habitat", serde_json::json!("sky")),
+        ]);
+        let errors = validate_entity(&validation_schema(), &entity).unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["name", "age", "habitat"]);
+    }
+
+    #[test]
+    fn save_entity_rejects_invalid_entity() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
 
-        create_entity(pp.clone(), "character".to_string(), "Boromir".to_string()).unwrap();
-        let entity_path = dir.path().join("entities/character/boromir.md");
-        assert!(entity_path.exists());
+        let entity = creature(&[("age", serde_json::json!(250))]);
+        let result = save_entity(pp.clone(), entity);
 
-        delete_entity(pp, "character".to_string(), "boromir".to_string()).unwrap();
-        assert!(!entity_path.exists());
+        match result {
+            Err(AppError::Validation(msg)) => {
+                assert!(msg.contains("name"));
+                assert!(msg.contains("age"));
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+        assert!(!dir.path().join("entities/creature/kraken.md").exists());
     }
 
     #[test]
-    fn delete_entity_nonexistent_returns_not_found() {
+    fn save_entity_draft_writes_invalid_entity_with_warnings() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
 
-        let result = delete_entity(pp, "character".to_string(), "nonexistent".to_string());
+        let entity = creature(&[("age", serde_json::json!(250))]);
+        let result = save_entity_draft(pp.clone(), entity).unwrap();
 
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
-        assert!(
-            err_msg.contains("Not found") || err_msg.contains("not found"),
-            "Expected 'not found' error, got: {}",
-            err_msg
-        );
+        assert_eq!(result.warnings.len(), 2);
+        assert!(result.warnings[0].starts_with("name: "));
+        assert!(result.warnings[1].starts_with("age: "));
+        assert!(dir.path().join("entities/creature/kraken.md").exists());
     }
 
-    // ── rename_entity ───────────────────────────────────────────────
-
     #[test]
-    fn rename_entity_updates_title_and_slug() {
+    fn rename_entity_rejects_invalid_entity() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
+        for schema in default_schemas() {
+            save_schema(pp.clone(), schema).unwrap();
+        }
 
-        create_entity(pp.clone(), "character".to_string(), "Strider".to_string()).unwrap();
+        // A new character has an empty required role; drafts may still be saved.
+        let entity =
+            create_entity(pp.clone(), "character".to_string(), "Mira".to_string()).unwrap();
+        save_entity_draft(pp.clone(), entity).unwrap();
 
-        let renamed = rename_entity(
+        let result = rename_entity(
             pp.clone(),
             "character".to_string(),
-            "strider".to_string(),
-            "Aragorn Son of Arathorn".to_string(),
-        )
-        .unwrap();
+            "mira".to_string(),
+            "Mira Vale".to_string(),
+        );
 
-        assert_eq!(renamed.title, "Aragorn Son of Arathorn");
-        assert_eq!(renamed.slug, "aragorn-son-of-arathorn");
-        assert_eq!(renamed.schema_slug, "character");
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert!(dir.path().join("entities/character/mira.md").exists());
+        assert!(!dir.path().join("entities/character/mira-vale.md").exists());
+    }
 
-        // Old file should be gone
-        assert!(!dir.path().join("entities/character/strider.md").exists());
-        // New file should exist
-        assert!(dir
-            .path()
-            .join("entities/character/aragorn-son-of-arathorn.md")
-            .exists());
+    #[test]
+    fn save_entity_accepts_valid_entity_with_schema() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
 
-        // Should be retrievable by new slug
+        let entity = creature(&[("name", serde_json::json!("Kraken"))]);
+        save_entity(pp.clone(), entity).unwrap();
+
+        assert!(dir.path().join("entities/creature/kraken.md").exists());
+    }
+
+    /// Helper: an unsaved "rival" entity (see `spider_schema`) with the given spider values.
+    fn rival_with_spider(pp: &str, values: &[(&str, f64)]) -> EntityInstance {
+        let mut entity =
+            create_entity(pp.to_string(), "rival".to_string(), "Vex".to_string()).unwrap();
+        entity.spider_values = values.iter().map(|(k, v)| (k.to_string(), *v)).collect();
+        entity
+    }
+
+    #[test]
+    fn save_entity_clamps_spider_value_above_max() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), spider_schema()).unwrap();
+
+        let result =
+            save_entity(pp.clone(), rival_with_spider(&pp, &[("Strength", 14.0)])).unwrap();
+        assert_eq!(result.entity.spider_values.get("Strength"), Some(&10.0));
+        assert!(result.warnings.is_empty());
+
+        let loaded = get_entity(pp, "rival".to_string(), "vex".to_string()).unwrap();
+        assert_eq!(loaded.spider_values.get("Strength"), Some(&10.0));
+    }
+
+    #[test]
+    fn save_entity_clamps_spider_value_below_min() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), spider_schema()).unwrap();
+
+        save_entity(pp.clone(), rival_with_spider(&pp, &[("Strength", -3.0)])).unwrap();
+
+        let loaded = get_entity(pp, "rival".to_string(), "vex".to_string()).unwrap();
+        assert_eq!(loaded.spider_values.get("Strength"), Some(&0.0));
+    }
+
+    #[test]
+    fn save_entity_rejects_non_finite_spider_value() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), spider_schema()).unwrap();
+
+        let entity = rival_with_spider(&pp, &[]);
+        let path = dir.path().join("entities/rival/vex.md");
+        let before = std::fs::read_to_string(&path).unwrap();
+
+        for value in [f64::NAN, f64::INFINITY] {
+            let mut entity = entity.clone();
+            entity.spider_values.insert("Strength".to_string(), value);
+            let result = save_entity(pp.clone(), entity);
+            assert!(matches!(result, Err(AppError::Validation(_))));
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), before);
+        }
+    }
+
+    #[test]
+    fn save_entity_drops_orphan_spider_axis_with_warning() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), spider_schema()).unwrap();
+
+        let entity = rival_with_spider(&pp, &[("Strength", 4.0), ("Charm", 7.0)]);
+        let result = save_entity(pp.clone(), entity).unwrap();
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("Charm"));
+
+        let loaded = get_entity(pp, "rival".to_string(), "vex".to_string()).unwrap();
+        assert_eq!(loaded.spider_values.get("Strength"), Some(&4.0));
+        assert!(!loaded.spider_values.contains_key("Charm"));
+    }
+
+    // ── compare_spider_values ───────────────────────────────────────
+
+    /// Helper: a schema with two spider axes and no required fields.
+    fn spider_schema() -> EntitySchema {
+        let axis = |name: &str, default: f64| SpiderAxis {
+            name: name.to_string(),
+            min: 0.0,
+            max: 10.0,
+            default,
+            description: None,
+        };
+        EntitySchema {
+            name: "Rival".to_string(),
+            entity_type: "rival".to_string(),
+            icon: None,
+            color: None,
+            description: None,
+            fields: vec![],
+            spider_axes: vec![axis("Cunning", 5.0), axis("Strength", 3.0)],
+        }
+    }
+
+    fn create_rival(pp: &str, title: &str, values: &[(&str, f64)]) {
+        let mut entity =
+            create_entity(pp.to_string(), "rival".to_string(), title.to_string()).unwrap();
+        for (axis, value) in values {
+            entity.spider_values.insert(axis.to_string(), *value);
+        }
+        save_entity(pp.to_string(), entity).unwrap();
+    }
+
+    #[test]
+    fn compare_spider_values_two_entities() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), spider_schema()).unwrap();
+        create_rival(&pp, "Ash", &[("Cunning", 9.0), ("Strength", 2.0)]);
+        create_rival(&pp, "Briar", &[("Cunning", 4.0), ("Strength", 8.0)]);
+
+        let cmp = compare_spider_values(
+            pp,
+            "rival".to_string(),
+            vec!["briar".to_string(), "ash".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(cmp.schema_type, "rival");
+        let slugs: Vec<&str> = cmp.entities.iter().map(|e| e.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["briar", "ash"]);
+        assert_eq!(cmp.entities[1].title, "Ash");
+
+        assert_eq!(cmp.axes.len(), 2);
+        assert_eq!(cmp.axes[0].name, "Cunning");
+        assert_eq!(cmp.axes[0].min, 0.0);
+        assert_eq!(cmp.axes[0].max, 10.0);
+        assert_eq!(cmp.axes[0].values, vec![4.0, 9.0]);
+        assert_eq!(cmp.axes[1].values, vec![8.0, 2.0]);
+    }
+
+    #[test]
+    fn compare_spider_values_falls_back_to_axis_default() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), spider_schema()).unwrap();
+        create_rival(&pp, "Ash", &[("Cunning", 9.0)]);
+
+        let cmp = compare_spider_values(pp, "rival".to_string(), vec!["ash".to_string()]).unwrap();
+
+        assert_eq!(cmp.axes[0].values, vec![9.0]);
+        assert_eq!(cmp.axes[1].values, vec![3.0]);
+    }
+
+    #[test]
+    fn compare_spider_values_missing_entity_returns_not_found() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), spider_schema()).unwrap();
+
+        let result = compare_spider_values(pp, "rival".to_string(), vec!["ghost".to_string()]);
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    // ── Entity templates ────────────────────────────────────────────
+
+    fn villain_fields() -> HashMap<String, serde_json::Value> {
+        HashMap::from([
+            ("role".to_string(), serde_json::json!("antagonist")),
+            ("occupation".to_string(), serde_json::json!("warlord")),
+        ])
+    }
+
+    #[test]
+    fn save_entity_template_writes_yaml() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let template = save_entity_template(
+            pp.clone(),
+            "character".to_string(),
+            "Stock Villain".to_string(),
+            villain_fields(),
+        )
+        .unwrap();
+        assert_eq!(template.name, "Stock Villain");
+
+        assert!(dir
+            .path()
+            .join("templates/character/stock-villain.yaml")
+            .exists());
+
+        let templates = list_entity_templates(pp, "character".to_string()).unwrap();
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].name, "Stock Villain");
+        assert_eq!(
+            templates[0].fields.get("role"),
+            Some(&serde_json::json!("antagonist"))
+        );
+    }
+
+    #[test]
+    fn list_entity_templates_empty_without_directory() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let templates = list_entity_templates(pp, "character".to_string()).unwrap();
+        assert!(templates.is_empty());
+    }
+
+    #[test]
+    fn list_entity_templates_sorted_and_scoped_to_schema() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        for name in ["Mentor", "Hero"] {
+            save_entity_template(
+                pp.clone(),
+                "character".to_string(),
+                name.to_string(),
+                HashMap::new(),
+            )
+            .unwrap();
+        }
+        save_entity_template(
+            pp.clone(),
+            "place".to_string(),
+            "Tavern".to_string(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        let templates = list_entity_templates(pp, "character".to_string()).unwrap();
+        let names: Vec<&str> = templates.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["Hero", "Mentor"]);
+    }
+
+    #[test]
+    fn create_entity_from_template_prefills_fields_and_spider_defaults() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), character_schema()).unwrap();
+        save_entity_template(
+            pp.clone(),
+            "character".to_string(),
+            "Stock Villain".to_string(),
+            villain_fields(),
+        )
+        .unwrap();
+
+        let entity = create_entity_from_template(
+            pp.clone(),
+            "character".to_string(),
+            "Lord Vex".to_string(),
+            "Stock Villain".to_string(),
+        )
+        .unwrap();
+        assert_eq!(entity.slug, "lord-vex");
+
+        let loaded = get_entity(pp, "character".to_string(), "lord-vex".to_string()).unwrap();
+        assert_eq!(loaded.title, "Lord Vex");
+        assert_eq!(loaded.fields, villain_fields());
+        let schema = character_schema();
+        assert_eq!(loaded.spider_values.len(), schema.spider_axes.len());
+        for axis in &schema.spider_axes {
+            assert_eq!(loaded.spider_values.get(&axis.name), Some(&axis.default));
+        }
+    }
+
+    #[test]
+    fn create_entity_from_nonexistent_template_returns_not_found() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), character_schema()).unwrap();
+
+        let result = create_entity_from_template(
+            pp,
+            "character".to_string(),
+            "Lord Vex".to_string(),
+            "Missing".to_string(),
+        );
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+        assert!(!dir.path().join("entities/character/lord-vex.md").exists());
+    }
+
+    // ── import_entities_csv ─────────────────────────────────────────
+
+    #[test]
+    fn import_entities_csv_clean_import() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
+
+        let csv = "title,name,age,habitat,tags\n\
+                   Kraken,Kraken,30,sea,monster; deep\n\
+                   Griffin,Griffin,12,land,\n";
+        let report =
+            import_entities_csv(pp.clone(), "creature".to_string(), csv.to_string()).unwrap();
+
+        assert!(report.failed.is_empty());
+        let slugs: Vec<&str> = report.imported.iter().map(|e| e.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["kraken", "griffin"]);
+
+        let kraken = get_entity(pp, "creature".to_string(), "kraken".to_string()).unwrap();
+        assert_eq!(kraken.tags, vec!["monster", "deep"]);
+        assert_eq!(kraken.fields.get("age"), Some(&serde_json::json!(30.0)));
+        assert_eq!(
+            kraken.fields.get("habitat"),
+            Some(&serde_json::json!("sea"))
+        );
+    }
+
+    #[test]
+    fn import_entities_csv_duplicate_titles_get_unique_slugs() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
+
+        let csv = "title,name\nKraken,First\nKraken,Second\n";
+        let report =
+            import_entities_csv(pp.clone(), "creature".to_string(), csv.to_string()).unwrap();
+
+        let slugs: Vec<&str> = report.imported.iter().map(|e| e.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["kraken", "kraken-2"]);
+
+        let second = get_entity(pp, "creature".to_string(), "kraken-2".to_string()).unwrap();
+        assert_eq!(second.title, "Kraken");
+        assert_eq!(
+            second.fields.get("name"),
+            Some(&serde_json::json!("Second"))
+        );
+    }
+
+    #[test]
+    fn import_entities_csv_reports_invalid_rows_and_keeps_going() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
+
+        let csv = "title,name,habitat\nKraken,Kraken,sky\nGriffin,Griffin,land\n";
+        let report =
+            import_entities_csv(pp.clone(), "creature".to_string(), csv.to_string()).unwrap();
+
+        assert_eq!(report.imported.len(), 1);
+        assert_eq!(report.imported[0].slug, "griffin");
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].line, 2);
+        assert_eq!(report.failed[0].title, "Kraken");
+        assert_eq!(report.failed[0].errors[0].field, "habitat");
+        assert!(!dir.path().join("entities/creature/kraken.md").exists());
+    }
+
+    #[test]
+    fn import_entities_csv_reports_ragged_row_and_keeps_going() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
+
+        let csv = "title,name\nKraken,Kraken,extra\nGriffin,Griffin\n";
+        let report =
+            import_entities_csv(pp.clone(), "creature".to_string(), csv.to_string()).unwrap();
+
+        assert_eq!(report.imported.len(), 1);
+        assert_eq!(report.imported[0].slug, "griffin");
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].line, 2);
+        assert_eq!(report.failed[0].errors[0].field, "row");
+    }
+
+    #[test]
+    fn import_entities_csv_line_numbers_count_multiline_cells() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
+
+        let csv = "title,name,habitat\n\
+                   Kraken,\"Kraken\nof the deep\",sea\n\
+                   Griffin,Griffin,sky\n";
+        let report =
+            import_entities_csv(pp.clone(), "creature".to_string(), csv.to_string()).unwrap();
+
+        assert_eq!(report.imported.len(), 1);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].title, "Griffin");
+        assert_eq!(report.failed[0].line, 4);
+    }
+
+    #[test]
+    fn import_entities_csv_rejects_unknown_column() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
+
+        let csv = "title,wingspan\nGriffin,12\n";
+        let result = import_entities_csv(pp, "creature".to_string(), csv.to_string());
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn import_entities_csv_requires_title_column() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_schema(pp.clone(), validation_schema()).unwrap();
+
+        let result = import_entities_csv(pp, "creature".to_string(), "name\nKraken\n".to_string());
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    // ── delete_entity ───────────────────────────────────────────────
+
+    #[test]
+    fn delete_entity_removes_file() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        create_entity(pp.clone(), "character".to_string(), "Boromir".to_string()).unwrap();
+        let entity_path = dir.path().join("entities/character/boromir.md");
+        assert!(entity_path.exists());
+
+        delete_entity(pp, "character".to_string(), "boromir".to_string()).unwrap();
+        assert!(!entity_path.exists());
+    }
+
+    #[test]
+    fn delete_entity_nonexistent_returns_not_found() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let result = delete_entity(pp, "character".to_string(), "nonexistent".to_string());
+
+        assert!(result.is_err());
+        let err_msg = result.unwrap_err().to_string();
+        assert!(
+            err_msg.contains("Not found") || err_msg.contains("not found"),
+            "Expected 'not found' error, got: {}",
+            err_msg
+        );
+    }
+
+    // ── rename_entity ───────────────────────────────────────────────
+
+    #[test]
+    fn rename_entity_updates_title_and_slug() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        create_entity(pp.clone(), "character".to_string(), "Strider".to_string()).unwrap();
+
+        let renamed = rename_entity(
+            pp.clone(),
+            "character".to_string(),
+            "strider".to_string(),
+            "Aragorn Son of Arathorn".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(renamed.title, "Aragorn Son of Arathorn");
+        assert_eq!(renamed.slug, "aragorn-son-of-arathorn");
+        assert_eq!(renamed.schema_slug, "character");
+
+        // Old file should be gone
+        assert!(!dir.path().join("entities/character/strider.md").exists());
+        // New file should exist
+        assert!(dir
+            .path()
+            .join("entities/character/aragorn-son-of-arathorn.md")
+            .exists());
+
+        // Should be retrievable by new slug
         let loaded = get_entity(
             pp,
             "character".to_string(),
@@ -1557,6 +2835,33 @@ mod tests {
         assert_eq!(loaded.title, "Aragorn Son of Arathorn");
     }
 
+    #[test]
+    fn rename_entity_onto_existing_title_keeps_both() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_entity(pp.clone(), "place".to_string(), "Old Mill".to_string()).unwrap();
+        create_entity(pp.clone(), "place".to_string(), "Harbor".to_string()).unwrap();
+
+        let renamed = rename_entity(
+            pp.clone(),
+            "place".to_string(),
+            "harbor".to_string(),
+            "Old Mill".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(renamed.slug, "old-mill-2");
+        let original = get_entity(pp.clone(), "place".to_string(), "old-mill".to_string()).unwrap();
+        assert_eq!(original.title, "Old Mill");
+        let slugs: Vec<String> = list_entities(pp, "place".to_string())
+            .unwrap()
+            .into_iter()
+            .map(|e| e.slug)
+            .collect();
+        assert_eq!(slugs.len(), 2);
+        assert!(slugs.contains(&"old-mill-2".to_string()));
+    }
+
     #[test]
     fn rename_entity_same_slug_just_updates_title() {
         let dir = setup_test_dir();
@@ -1584,4 +2889,219 @@ mod tests {
         let loaded = get_entity(pp, "character".to_string(), "frodo".to_string()).unwrap();
         assert_eq!(loaded.title, "FRODO");
     }
+
+    // ── Tags ────────────────────────────────────────────────────────
+
+    /// Helper: create an entity of `schema_type` with the given tags (no schema on disk).
+    fn create_tagged(pp: &str, schema_type: &str, title: &str, tags: &[&str]) {
+        let mut entity =
+            create_entity(pp.to_string(), schema_type.to_string(), title.to_string()).unwrap();
+        entity.tags = tags.iter().map(|t| t.to_string()).collect();
+        save_entity(pp.to_string(), entity).unwrap();
+    }
+
+    #[test]
+    fn list_entities_by_tag_spans_schemas() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        create_tagged(&pp, "character", "Frodo", &["fellowship", "hobbit"]);
+        create_tagged(&pp, "item", "Sting", &["fellowship"]);
+        create_tagged(&pp, "character", "Gollum", &["hobbit"]);
+
+        let results = list_entities_by_tag(pp, "fellowship".to_string()).unwrap();
+        let slugs: Vec<(&str, &str)> = results
+            .iter()
+            .map(|e| (e.schema_type.as_str(), e.slug.as_str()))
+            .collect();
+        assert_eq!(slugs, vec![("character", "frodo"), ("item", "sting")]);
+    }
+
+    #[test]
+    fn list_entities_by_tag_single_use_and_case_sensitive() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        create_tagged(&pp, "place", "Mordor", &["Dark"]);
+
+        let results = list_entities_by_tag(pp.clone(), "Dark".to_string()).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].slug, "mordor");
+
+        let results = list_entities_by_tag(pp, "dark".to_string()).unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn list_entities_by_tag_nonexistent_returns_empty() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        create_tagged(&pp, "character", "Frodo", &["hobbit"]);
+
+        let results = list_entities_by_tag(pp, "wizard".to_string()).unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn list_all_tags_counts_usage() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        create_tagged(&pp, "character", "Frodo", &["fellowship", "hobbit"]);
+        create_tagged(&pp, "item", "Sting", &["fellowship"]);
+        create_tagged(&pp, "place", "Shire", &["home"]);
+
+        let tags = list_all_tags(pp).unwrap();
+        assert_eq!(
+            tags,
+            vec![
+                TagCount {
+                    tag: "fellowship".to_string(),
+                    count: 2
+                },
+                TagCount {
+                    tag: "hobbit".to_string(),
+                    count: 1
+                },
+                TagCount {
+                    tag: "home".to_string(),
+                    count: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn list_all_tags_empty_project() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        assert!(list_all_tags(pp).unwrap().is_empty());
+    }
+
+    // ── Entity references ───────────────────────────────────────────
+
+    /// Helper: write the character schema with an extra `home` reference to places.
+    fn write_schemas_with_reference(pp: &str) {
+        let mut character = character_schema();
+        character.fields.push(EntityField {
+            name: "home".to_string(),
+            label: "Home".to_string(),
+            field_type: FieldType::Reference,
+            required: false,
+            placeholder: None,
+            description: None,
+            options: None,
+            min: None,
+            max: None,
+            reference_schema: Some("place".to_string()),
+        });
+        save_schema(pp.to_string(), character).unwrap();
+        save_schema(pp.to_string(), place_schema()).unwrap();
+    }
+
+    /// Helper: create a character whose `home` field points at `home`.
+    fn create_character_with_home(pp: &str, title: &str, home: &str) {
+        let mut entity =
+            create_entity(pp.to_string(), "character".to_string(), title.to_string()).unwrap();
+        entity.fields.insert(
+            "role".to_string(),
+            serde_json::Value::String("supporting".to_string()),
+        );
+        entity.fields.insert(
+            "home".to_string(),
+            serde_json::Value::String(home.to_string()),
+        );
+        save_entity(pp.to_string(), entity).unwrap();
+    }
+
+    #[test]
+    fn reference_field_serializes_type_and_schema_separately() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_schemas_with_reference(&pp);
+
+        let yaml = std::fs::read_to_string(dir.path().join("schemas/character.yaml")).unwrap();
+        assert!(yaml.contains("fieldType: reference\n"));
+        assert!(yaml.contains("referenceSchema: place\n"));
+
+        let schema = get_schema(pp, "character".to_string()).unwrap();
+        let home = schema.fields.iter().find(|f| f.name == "home").unwrap();
+        assert_eq!(home.field_type, FieldType::Reference);
+        assert_eq!(home.reference_schema.as_deref(), Some("place"));
+    }
+
+    #[test]
+    fn resolve_entity_reference_follows_valid_link() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_schemas_with_reference(&pp);
+
+        create_entity(pp.clone(), "place".to_string(), "The Shire".to_string()).unwrap();
+        create_character_with_home(&pp, "Frodo", "the-shire");
+
+        let target = resolve_entity_reference(
+            pp,
+            "character".to_string(),
+            "frodo".to_string(),
+            "home".to_string(),
+        )
+        .unwrap()
+        .expect("reference should resolve");
+        assert_eq!(target.title, "The Shire");
+        assert_eq!(target.schema_slug, "place");
+    }
+
+    #[test]
+    fn resolve_entity_reference_dangling_returns_none() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_schemas_with_reference(&pp);
+
+        create_character_with_home(&pp, "Frodo", "mordor");
+
+        let target = resolve_entity_reference(
+            pp,
+            "character".to_string(),
+            "frodo".to_string(),
+            "home".to_string(),
+        )
+        .unwrap();
+        assert!(target.is_none());
+    }
+
+    #[test]
+    fn resolve_entity_reference_rejects_non_reference_field() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_schemas_with_reference(&pp);
+
+        create_character_with_home(&pp, "Frodo", "the-shire");
+
+        let result = resolve_entity_reference(
+            pp,
+            "character".to_string(),
+            "frodo".to_string(),
+            "role".to_string(),
+        );
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn find_referencing_entities_returns_reverse_lookup() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_schemas_with_reference(&pp);
+
+        create_entity(pp.clone(), "place".to_string(), "The Shire".to_string()).unwrap();
+        create_character_with_home(&pp, "Frodo", "the-shire");
+        create_character_with_home(&pp, "Sam", "the-shire");
+        create_character_with_home(&pp, "Aragorn", "gondor");
+
+        let users =
+            find_referencing_entities(pp, "place".to_string(), "the-shire".to_string()).unwrap();
+        let slugs: Vec<&str> = users.iter().map(|e| e.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["frodo", "sam"]);
+    }
 }
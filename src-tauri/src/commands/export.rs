@@ -0,0 +1,762 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+
+use crate::commands::compile::{
+    html_escape, markdown_to_html, rewrite_wiki_links, HTML_STYLESHEET,
+};
+use crate::commands::entity::{get_entity, list_all_entities};
+use crate::commands::import::{merge_runs, StyledRun};
+use crate::commands::manuscript::{get_chapter, get_manuscript_config};
+use crate::commands::notes::{get_note, get_notes_config};
+use crate::commands::project::open_project;
+use crate::commands::search::{linkable_files, wiki_link_matches, WikiTarget};
+use crate::error::AppError;
+use crate::services::slug_service::slugify;
+
+/// Binder IDs reserved for Scrivener's three root folders.
+const DRAFT_FOLDER_ID: usize = 0;
+const RESEARCH_FOLDER_ID: usize = 1;
+const TRASH_FOLDER_ID: usize = 2;
+
+// ── RTF ───────────────────────────────────────────────────────────
+
+/// Split Markdown into paragraphs of bold/italic runs.
+///
+/// Headings and list items become their own paragraphs; soft breaks become
+/// spaces and hard breaks become line breaks within the paragraph.
+fn markdown_paragraphs(markdown: &str) -> Vec<Vec<StyledRun>> {
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<StyledRun> = Vec::new();
+    let mut bold = 0usize;
+    let mut italic = 0usize;
+
+    let push = |current: &mut Vec<StyledRun>, text: &str, bold: usize, italic: usize| {
+        current.push(StyledRun {
+            text: text.to_string(),
+            bold: bold > 0,
+            italic: italic > 0,
+        });
+    };
+
+    for event in Parser::new(markdown) {
+        match event {
+            Event::Start(Tag::Strong) => bold += 1,
+            Event::End(TagEnd::Strong) => bold = bold.saturating_sub(1),
+            Event::Start(Tag::Emphasis) => italic += 1,
+            Event::End(TagEnd::Emphasis) => italic = italic.saturating_sub(1),
+            Event::Text(text) | Event::Code(text) => push(&mut current, &text, bold, italic),
+            Event::SoftBreak => push(&mut current, " ", bold, italic),
+            Event::HardBreak => push(&mut current, "\n", bold, italic),
+            Event::End(TagEnd::Paragraph | TagEnd::Heading(_) | TagEnd::Item)
+                if !current.is_empty() =>
+            {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+            _ => {}
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs
+}
+
+/// Escape text for an RTF body, encoding non-ASCII as `\uN?` escapes.
+fn rtf_escape(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '{' => out.push_str("\\{"),
+            '}' => out.push_str("\\}"),
+            '\n' => out.push_str("\\line "),
+            '\t' => out.push_str("\\tab "),
+            c if c.is_ascii() => out.push(c),
+            c => {
+                let mut units = [0u16; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    out.push_str(&format!("\\u{}?", *unit as i16));
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Render Markdown as a minimal RTF document with bold/italic preserved.
+fn markdown_to_rtf(markdown: &str) -> String {
+    let mut out = String::from("{\\rtf1\\ansi\\deff0{\\fonttbl{\\f0 Times New Roman;}}\n");
+    for paragraph in markdown_paragraphs(markdown) {
+        for run in merge_runs(paragraph) {
+            let text = rtf_escape(&run.text);
+            match (run.bold, run.italic) {
+                (false, false) => out.push_str(&text),
+                (bold, italic) => {
+                    out.push('{');
+                    if bold {
+                        out.push_str("\\b");
+                    }
+                    if italic {
+                        out.push_str("\\i");
+                    }
+                    out.push(' ');
+                    out.push_str(&text);
+                    out.push('}');
+                }
+            }
+        }
+        out.push_str("\\par\n");
+    }
+    out.push('}');
+    out
+}
+
+// ── Binder ────────────────────────────────────────────────────────
+
+/// A binder entry: a text document or a folder of further entries.
+struct BinderItem {
+    id: usize,
+    kind: &'static str,
+    title: String,
+    synopsis: Option<String>,
+    children: Vec<BinderItem>,
+}
+
+impl BinderItem {
+    fn folder(id: usize, kind: &'static str, title: &str, children: Vec<BinderItem>) -> Self {
+        BinderItem {
+            id,
+            kind,
+            title: title.to_string(),
+            synopsis: None,
+            children,
+        }
+    }
+}
+
+/// Append a binder item and its children as `<BinderItem>` XML.
+fn write_binder_item(out: &mut String, item: &BinderItem, depth: usize) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&format!(
+        "{}<BinderItem ID=\"{}\" Type=\"{}\">\n",
+        indent, item.id, item.kind
+    ));
+    out.push_str(&format!(
+        "{}  <Title>{}</Title>\n",
+        indent,
+        html_escape(&item.title)
+    ));
+    if let Some(synopsis) = &item.synopsis {
+        out.push_str(&format!(
+            "{}  <Synopsis>{}</Synopsis>\n",
+            indent,
+            html_escape(synopsis)
+        ));
+    }
+    if !item.children.is_empty() {
+        out.push_str(&format!("{}  <Children>\n", indent));
+        for child in &item.children {
+            write_binder_item(out, child, depth + 2);
+        }
+        out.push_str(&format!("{}  </Children>\n", indent));
+    }
+    out.push_str(&format!("{}</BinderItem>\n", indent));
+}
+
+/// Render the `.scrivx` project XML for a binder.
+fn binder_xml(items: &[BinderItem]) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<ScrivenerProject Version=\"2.0\">\n  <Binder>\n");
+    for item in items {
+        write_binder_item(&mut out, item, 2);
+    }
+    out.push_str("  </Binder>\n</ScrivenerProject>\n");
+    out
+}
+
+/// Write a text document's RTF to `Files/Docs/{id}.rtf`.
+fn write_doc(docs_dir: &Path, id: usize, markdown: &str) -> Result<(), AppError> {
+    std::fs::write(
+        docs_dir.join(format!("{}.rtf", id)),
+        markdown_to_rtf(markdown),
+    )?;
+    Ok(())
+}
+
+// ── HTML ──────────────────────────────────────────────────────────
+
+/// Layout rules layered over the compile stylesheet: a fixed sidebar beside
+/// a scrolling content column. The sidebar is hidden when printing.
+const HTML_EXPORT_LAYOUT: &str = r#"
+    body {
+        display: flex;
+        max-width: none;
+        margin: 0;
+        padding: 0;
+    }
+    nav.sidebar {
+        position: sticky;
+        top: 0;
+        height: 100vh;
+        overflow-y: auto;
+        width: 16em;
+        flex-shrink: 0;
+        padding: 1em;
+        border-right: 1px solid #ddd;
+        font-size: 0.9em;
+    }
+    nav.sidebar h2 {
+        font-size: 1em;
+        text-align: left;
+        margin: 1em 0 0.5em 0;
+    }
+    nav.sidebar ul {
+        list-style: none;
+        margin: 0;
+        padding: 0;
+    }
+    main {
+        max-width: 720px;
+        margin: 2em auto;
+        padding: 0 1em;
+    }
+    main section {
+        margin-bottom: 3em;
+    }
+
+    @media print {
+        nav.sidebar {
+            display: none;
+        }
+    }
+"#;
+
+/// A chapter, entity or note rendered as one section of the HTML export.
+struct HtmlSection {
+    id: String,
+    title: String,
+    /// Sidebar heading the section is listed under.
+    group: String,
+    body: String,
+}
+
+/// The section id for a wiki-link target, or `None` for an ambiguous one.
+fn target_anchor(target: &WikiTarget) -> Option<String> {
+    match target {
+        WikiTarget::Chapter { slug } => Some(format!("chapter-{}", slug)),
+        WikiTarget::Note { slug } => Some(format!("note-{}", slug)),
+        WikiTarget::Entity { schema, slug } => Some(format!("entity-{}-{}", schema, slug)),
+        WikiTarget::Ambiguous(_) => None,
+    }
+}
+
+/// Render the sidebar, one list per group in first-appearance order.
+fn html_sidebar(sections: &[HtmlSection]) -> String {
+    let mut out = String::from("<nav class=\"sidebar\">\n");
+    let mut group: Option<&str> = None;
+    for section in sections {
+        if group != Some(section.group.as_str()) {
+            if group.is_some() {
+                out.push_str("</ul>\n");
+            }
+            out.push_str(&format!("<h2>{}</h2>\n<ul>\n", html_escape(&section.group)));
+            group = Some(&section.group);
+        }
+        out.push_str(&format!(
+            "<li><a href=\"#{}\">{}</a></li>\n",
+            section.id,
+            html_escape(&section.title)
+        ));
+    }
+    if group.is_some() {
+        out.push_str("</ul>\n");
+    }
+    out.push_str("</nav>");
+    out
+}
+
+/// Write a complete `.scriv` package for the project at `package`.
+fn write_scrivener_package(project_path: &str, package: &Path) -> Result<(), AppError> {
+    let docs_dir = package.join("Files").join("Docs");
+    std::fs::create_dir_all(&docs_dir)?;
+
+    let mut next_id = TRASH_FOLDER_ID + 1;
+
+    let mut drafts = Vec::new();
+    for slug in get_manuscript_config(project_path.to_string())?.chapters {
+        let chapter = match get_chapter(project_path.to_string(), slug) {
+            Ok(chapter) => chapter,
+            Err(AppError::NotFound(_)) => continue,
+            Err(e) => return Err(e),
+        };
+        write_doc(&docs_dir, next_id, &chapter.body)?;
+        drafts.push(BinderItem {
+            id: next_id,
+            kind: "Text",
+            title: chapter.frontmatter.title,
+            synopsis: chapter.frontmatter.synopsis,
+            children: vec![],
+        });
+        next_id += 1;
+    }
+
+    let mut entities = list_all_entities(project_path)?;
+    entities.sort_by(|a, b| a.schema_type.cmp(&b.schema_type));
+    let mut research: Vec<BinderItem> = Vec::new();
+    for summary in entities {
+        if research.last().map(|f| &f.title) != Some(&summary.schema_type) {
+            research.push(BinderItem::folder(
+                next_id,
+                "Folder",
+                &summary.schema_type,
+                vec![],
+            ));
+            next_id += 1;
+        }
+        let entity = get_entity(
+            project_path.to_string(),
+            summary.schema_type.clone(),
+            summary.slug,
+        )?;
+        write_doc(&docs_dir, next_id, &entity.body)?;
+        if let Some(folder) = research.last_mut() {
+            folder.children.push(BinderItem {
+                id: next_id,
+                kind: "Text",
+                title: entity.title,
+                synopsis: None,
+                children: vec![],
+            });
+        }
+        next_id += 1;
+    }
+
+    let binder = [
+        BinderItem::folder(DRAFT_FOLDER_ID, "DraftFolder", "Draft", drafts),
+        BinderItem::folder(RESEARCH_FOLDER_ID, "ResearchFolder", "Research", research),
+        BinderItem::folder(TRASH_FOLDER_ID, "TrashFolder", "Trash", vec![]),
+    ];
+    std::fs::write(package.join("project.scrivx"), binder_xml(&binder))?;
+    Ok(())
+}
+
+// ── Commands ──────────────────────────────────────────────────────
+
+/// Export the project as a Scrivener `.scriv` package inside `out_dir`.
+///
+/// Chapters go in the Draft folder in manuscript order, each with an RTF file
+/// under `Files/Docs` and its synopsis in the binder. Entities go in the
+/// Research folder, grouped by schema type. Missing chapter files are skipped.
+/// The package is built in a hidden staging directory and moved into place
+/// once complete, so a failed export leaves nothing behind.
+/// Returns the path of the created package.
+#[tauri::command]
+pub fn export_scrivener(project_path: String, out_dir: String) -> Result<String, AppError> {
+    let manifest = open_project(project_path.clone())?;
+    let name = match slugify(&manifest.name) {
+        slug if slug.is_empty() => "project".to_string(),
+        slug => slug,
+    };
+    let package = PathBuf::from(&out_dir).join(format!("{}.scriv", name));
+    if package.exists() {
+        return Err(AppError::AlreadyExists(format!(
+            "Export already exists: {}",
+            package.display()
+        )));
+    }
+
+    let staging = PathBuf::from(&out_dir).join(format!(".{}.scriv.partial", name));
+    if staging.exists() {
+        std::fs::remove_dir_all(&staging)?;
+    }
+    if let Err(e) = write_scrivener_package(&project_path, &staging) {
+        // Best effort: the export error is more useful than a cleanup error.
+        let _ = std::fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+    std::fs::rename(&staging, &package)?;
+
+    Ok(package.to_string_lossy().to_string())
+}
+
+/// Export the whole project as a single self-contained HTML document.
+///
+/// A sidebar lists every chapter (in manuscript order), entity (grouped by
+/// schema type) and note (in notes order), each linking to its own section.
+/// Wiki links that resolve to an exported section become in-page links; the
+/// rest are rendered as the linked title, or the bare link text when nothing
+/// matches. Missing chapter and note files are skipped.
+#[tauri::command]
+pub fn export_project_html(project_path: String) -> Result<String, AppError> {
+    let manifest = open_project(project_path.clone())?;
+    let mut sections = Vec::new();
+
+    for slug in get_manuscript_config(project_path.clone())?.chapters {
+        let chapter = match get_chapter(project_path.clone(), slug) {
+            Ok(chapter) => chapter,
+            Err(AppError::NotFound(_)) => continue,
+            Err(e) => return Err(e),
+        };
+        sections.push(HtmlSection {
+            id: format!("chapter-{}", chapter.slug),
+            title: chapter.frontmatter.title,
+            group: "Chapters".to_string(),
+            body: chapter.body,
+        });
+    }
+
+    let mut entities = list_all_entities(&project_path)?;
+    entities.sort_by(|a, b| a.schema_type.cmp(&b.schema_type));
+    for summary in entities {
+        let entity = get_entity(
+            project_path.clone(),
+            summary.schema_type.clone(),
+            summary.slug,
+        )?;
+        sections.push(HtmlSection {
+            id: format!("entity-{}-{}", summary.schema_type, entity.slug),
+            title: entity.title,
+            group: summary.schema_type,
+            body: entity.body,
+        });
+    }
+
+    for entry in get_notes_config(project_path.clone())?.notes {
+        let note = match get_note(project_path.clone(), entry.slug) {
+            Ok(note) => note,
+            Err(AppError::NotFound(_)) => continue,
+            Err(e) => return Err(e),
+        };
+        sections.push(HtmlSection {
+            id: format!("note-{}", note.slug),
+            title: note.title,
+            group: "Notes".to_string(),
+            body: note.body,
+        });
+    }
+
+    let files = linkable_files(&PathBuf::from(&project_path));
+    let anchors: HashSet<&str> = sections.iter().map(|s| s.id.as_str()).collect();
+    let link_html = |target: &str| {
+        // Ambiguous matches share a title, so the first one will do.
+        let Some(file) = wiki_link_matches(&files, target).into_iter().next() else {
+            return Some(html_escape(target));
+        };
+        let title = html_escape(&file.title);
+        Some(match target_anchor(&file.target) {
+            Some(id) if anchors.contains(id.as_str()) => {
+                format!("<a href=\"#{}\">{}</a>", id, title)
+            }
+            _ => title,
+        })
+    };
+
+    let mut content = String::new();
+    for section in &sections {
+        content.push_str(&format!(
+            "<section id=\"{}\">\n<h2>{}</h2>\n{}</section>\n",
+            section.id,
+            html_escape(&section.title),
+            markdown_to_html(&rewrite_wiki_links(&section.body, link_html), &[])
+        ));
+    }
+
+    Ok(format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{title}</title>
+    <style>{stylesheet}{layout}</style>
+</head>
+<body>
+{sidebar}
+<main>
+<h1>{title}</h1>
+{content}</main>
+</body>
+</html>"#,
+        title = html_escape(&manifest.name),
+        stylesheet = HTML_STYLESHEET,
+        layout = HTML_EXPORT_LAYOUT,
+        sidebar = html_sidebar(&sections),
+        content = content,
+    ))
+}
+
+// ── Tests ─────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::entity::{create_entity, save_entity};
+    use crate::commands::manuscript::{create_chapter, reorder_chapters, save_chapter};
+    use crate::commands::notes::{create_note, save_note};
+    use crate::test_helpers::{setup_test_dir, setup_test_project};
+
+    fn write_chapter(pp: &str, title: &str, synopsis: Option<&str>, body: &str) -> String {
+        let mut chapter = create_chapter(pp.to_string(), title.to_string()).unwrap();
+        chapter.frontmatter.synopsis = synopsis.map(str::to_string);
+        save_chapter(
+            pp.to_string(),
+            chapter.slug.clone(),
+            chapter.frontmatter,
+            body.to_string(),
+        )
+        .unwrap();
+        chapter.slug
+    }
+
+    /// Binder `<Title>`s under the Draft folder, in document order.
+    fn draft_titles(scrivx: &str) -> Vec<String> {
+        let doc = roxmltree::Document::parse(scrivx).unwrap();
+        let draft = doc
+            .descendants()
+            .find(|n| n.attribute("Type") == Some("DraftFolder"))
+            .unwrap();
+        draft
+            .descendants()
+            .filter(|n| n.attribute("Type") == Some("Text"))
+            .map(|item| {
+                item.children()
+                    .find(|c| c.has_tag_name("Title"))
+                    .and_then(|t| t.text())
+                    .unwrap_or("")
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn export_scrivener_binder_lists_chapters_in_config_order() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+        let first = write_chapter(&pp, "Arrival", Some("They land."), "Rain.");
+        let second = write_chapter(&pp, "Departure", None, "Sun.");
+        reorder_chapters(pp.clone(), vec![second, first]).unwrap();
+        let out = setup_test_dir();
+
+        let package = export_scrivener(pp, out.path().to_str().unwrap().to_string()).unwrap();
+
+        assert!(package.ends_with("test-project.scriv"));
+        let scrivx =
+            std::fs::read_to_string(PathBuf::from(&package).join("project.scrivx")).unwrap();
+        assert_eq!(draft_titles(&scrivx), vec!["Departure", "Arrival"]);
+        assert!(scrivx.contains("<Synopsis>They land.</Synopsis>"));
+    }
+
+    #[test]
+    fn export_scrivener_writes_a_doc_per_chapter() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+        write_chapter(&pp, "One", None, "Plain and **bold** and *slanted*.");
+        write_chapter(&pp, "Two", None, "Second.");
+        let out = setup_test_dir();
+
+        let package = export_scrivener(pp, out.path().to_str().unwrap().to_string()).unwrap();
+
+        let docs = PathBuf::from(&package).join("Files").join("Docs");
+        let one = std::fs::read_to_string(docs.join("3.rtf")).unwrap();
+        let two = std::fs::read_to_string(docs.join("4.rtf")).unwrap();
+        assert!(one.starts_with("{\\rtf1"));
+        assert!(one.contains("Plain and {\\b bold} and {\\i slanted}.\\par"));
+        assert!(two.contains("Second.\\par"));
+        assert_eq!(std::fs::read_dir(&docs).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn export_scrivener_puts_entities_under_research() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+        let mut entity =
+            create_entity(pp.clone(), "character".to_string(), "Mira".to_string()).unwrap();
+        entity.body = "A cartographer.".to_string();
+        save_entity(pp.clone(), entity).unwrap();
+        let out = setup_test_dir();
+
+        let package = export_scrivener(pp, out.path().to_str().unwrap().to_string()).unwrap();
+
+        let scrivx =
+            std::fs::read_to_string(PathBuf::from(&package).join("project.scrivx")).unwrap();
+        let doc = roxmltree::Document::parse(&scrivx).unwrap();
+        let research = doc
+            .descendants()
+            .find(|n| n.attribute("Type") == Some("ResearchFolder"))
+            .unwrap();
+        let titles: Vec<&str> = research
+            .descendants()
+            .filter(|n| n.has_tag_name("Title"))
+            .filter_map(|n| n.text())
+            .collect();
+        assert_eq!(titles, vec!["Research", "character", "Mira"]);
+        let rtf =
+            std::fs::read_to_string(PathBuf::from(&package).join("Files/Docs/4.rtf")).unwrap();
+        assert!(rtf.contains("A cartographer.\\par"));
+    }
+
+    #[test]
+    fn export_scrivener_existing_package_returns_already_exists() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+        let out = setup_test_dir();
+        let out_path = out.path().to_str().unwrap().to_string();
+
+        export_scrivener(pp.clone(), out_path.clone()).unwrap();
+        let result = export_scrivener(pp, out_path);
+
+        assert!(matches!(result, Err(AppError::AlreadyExists(_))));
+    }
+
+    #[test]
+    fn export_scrivener_failure_leaves_no_package() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+        write_chapter(&pp, "One", None, "Kept.");
+        let entity_dir = root.join("entities").join("character");
+        std::fs::create_dir_all(&entity_dir).unwrap();
+        std::fs::write(entity_dir.join("broken.md"), "---\ntitle: [unclosed\n---\n").unwrap();
+        let out = setup_test_dir();
+        let out_path = out.path().to_str().unwrap().to_string();
+
+        assert!(export_scrivener(pp.clone(), out_path.clone()).is_err());
+        assert_eq!(std::fs::read_dir(out.path()).unwrap().count(), 0);
+
+        std::fs::remove_file(entity_dir.join("broken.md")).unwrap();
+        let package = export_scrivener(pp, out_path).unwrap();
+        assert!(PathBuf::from(&package).join("project.scrivx").exists());
+        assert_eq!(std::fs::read_dir(out.path()).unwrap().count(), 1);
+    }
+
+    fn write_entity(pp: &str, schema: &str, title: &str, body: &str) -> String {
+        let mut entity =
+            create_entity(pp.to_string(), schema.to_string(), title.to_string()).unwrap();
+        entity.body = body.to_string();
+        let slug = entity.slug.clone();
+        save_entity(pp.to_string(), entity).unwrap();
+        slug
+    }
+
+    fn write_note(pp: &str, title: &str, body: &str) -> String {
+        let note = create_note(pp.to_string(), title.to_string()).unwrap();
+        save_note(
+            pp.to_string(),
+            note.slug.clone(),
+            note.title,
+            body.to_string(),
+        )
+        .unwrap();
+        note.slug
+    }
+
+    /// The `<nav class="sidebar">` element of an exported document.
+    fn sidebar(html: &str) -> &str {
+        let start = html.find("<nav class=\"sidebar\">").unwrap();
+        let end = html[start..].find("</nav>").unwrap();
+        &html[start..start + end]
+    }
+
+    // ── export_project_html ───────────────────────────────────────
+
+    #[test]
+    fn export_project_html_has_a_section_per_content_type() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+        write_chapter(&pp, "Arrival", None, "Rain on *glass*.");
+        write_entity(&pp, "character", "Mira", "A cartographer.");
+        write_note(&pp, "Ideas", "Maybe a storm.");
+
+        let html = export_project_html(pp).unwrap();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Test Project</title>"));
+        assert!(html.contains(
+            "<section id=\"chapter-arrival\">\n<h2>Arrival</h2>\n<p>Rain on <em>glass</em>.</p>"
+        ));
+        assert!(html.contains(
+            "<section id=\"entity-character-mira\">\n<h2>Mira</h2>\n<p>A cartographer.</p>"
+        ));
+        assert!(html.contains("<section id=\"note-ideas\">\n<h2>Ideas</h2>\n<p>Maybe a storm.</p>"));
+    }
+
+    #[test]
+    fn export_project_html_nav_lists_every_section() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+        let first = write_chapter(&pp, "Arrival", None, "One.");
+        let second = write_chapter(&pp, "Departure", None, "Two.");
+        reorder_chapters(pp.clone(), vec![second, first]).unwrap();
+        write_entity(&pp, "character", "Mira", "");
+        write_entity(&pp, "place", "Harbor", "");
+        write_note(&pp, "Ideas", "");
+
+        let html = export_project_html(pp).unwrap();
+
+        let nav = sidebar(&html);
+        assert_eq!(nav.matches("<li>").count(), 5);
+        assert_eq!(nav.matches("<h2>").count(), 4);
+        let departure = nav.find("href=\"#chapter-departure\"").unwrap();
+        let arrival = nav.find("href=\"#chapter-arrival\"").unwrap();
+        assert!(departure < arrival);
+        assert!(nav.contains(
+            "<h2>character</h2>\n<ul>\n<li><a href=\"#entity-character-mira\">Mira</a></li>"
+        ));
+    }
+
+    #[test]
+    fn export_project_html_empty_project_has_empty_nav() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+
+        let html = export_project_html(pp).unwrap();
+
+        assert_eq!(sidebar(&html), "<nav class=\"sidebar\">\n");
+        assert!(!html.contains("<section"));
+    }
+
+    #[test]
+    fn export_project_html_resolves_wiki_links_to_anchors() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+        write_entity(&pp, "character", "Mira", "");
+        write_note(&pp, "Ideas", "");
+        write_chapter(
+            &pp,
+            "Arrival",
+            None,
+            "[[Mira]] reads [[note:Ideas]] about [[Nowhere]].",
+        );
+
+        let html = export_project_html(pp).unwrap();
+
+        assert!(html.contains(
+            "<p><a href=\"#entity-character-mira\">Mira</a> reads \
+             <a href=\"#note-ideas\">Ideas</a> about Nowhere.</p>"
+        ));
+    }
+
+    #[test]
+    fn export_project_html_unexported_chapter_link_renders_title() {
+        let (_dir, root) = setup_test_project();
+        let pp = root.to_str().unwrap().to_string();
+        let cut = write_chapter(&pp, "Cut Scene", None, "Gone.");
+        let kept = write_chapter(&pp, "Arrival", None, "See [[Cut Scene]].");
+        reorder_chapters(pp.clone(), vec![kept]).unwrap();
+
+        let html = export_project_html(pp).unwrap();
+
+        assert!(!html.contains(&format!("id=\"chapter-{}\"", cut)));
+        assert!(html.contains("<p>See Cut Scene.</p>"));
+    }
+
+    // ── RTF ───────────────────────────────────────────────────────
+
+    #[test]
+    fn rtf_escape_handles_braces_and_unicode() {
+        assert_eq!(rtf_escape("{a\\b}"), "\\{a\\\\b\\}");
+        assert_eq!(rtf_escape("é"), "\\u233?");
+        assert_eq!(rtf_escape("😀"), "\\u-10179?\\u-8704?");
+    }
+}
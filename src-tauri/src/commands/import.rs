@@ -0,0 +1,566 @@
+use std::io::{Cursor, Read};
+
+use regex::Regex;
+
+use crate::commands::manuscript::{
+    chapter_file_slugs, get_manuscript_config, save_chapter, save_manuscript_config,
+};
+use crate::error::AppError;
+use crate::models::manuscript::{Chapter, ChapterStatus};
+use crate::services::slug_service::{slugify, unique_slug};
+
+/// WordprocessingML namespace used by every element in `word/document.xml`.
+const WORDML_NS: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+
+/// Default chapter break for plain-text import: a `Chapter N` line or a form feed.
+const DEFAULT_SPLIT_PATTERN: &str = r"(?m)^Chapter \d+.*$|\x0C";
+
+// ── Helpers ───────────────────────────────────────────────────────
+
+/// Create chapters from imported titles and Markdown bodies, returning their
+/// slugs in order.
+///
+/// Every slug is chosen before anything is written: a title that collides
+/// with an existing chapter or an earlier imported one gets a numeric suffix,
+/// so the import can't stop halfway on a duplicate. Titles with no usable
+/// slug are filed as "untitled".
+fn create_imported_chapters(
+    project_path: &str,
+    chapters: Vec<(String, String)>,
+) -> Result<Vec<String>, AppError> {
+    let mut taken = chapter_file_slugs(project_path)?;
+    let mut planned = Vec::with_capacity(chapters.len());
+    for (title, body) in chapters {
+        let base = match slugify(&title) {
+            slug if slug.is_empty() => "untitled".to_string(),
+            slug => slug,
+        };
+        let slug = unique_slug(&taken, &base);
+        taken.push(slug.clone());
+        planned.push((slug, title, body));
+    }
+
+    let mut config = get_manuscript_config(project_path.to_string())?;
+    let mut slugs = Vec::with_capacity(planned.len());
+    for (slug, title, body) in planned {
+        let chapter = Chapter {
+            slug: slug.clone(),
+            title,
+            status: ChapterStatus::Draft,
+            pov: None,
+            synopsis: None,
+            target_words: None,
+            order: config.chapters.len() as u32,
+        };
+        save_chapter(project_path.to_string(), slug.clone(), chapter, body)?;
+        config.chapters.push(slug.clone());
+        slugs.push(slug);
+    }
+    save_manuscript_config(project_path.to_string(), config)?;
+    Ok(slugs)
+}
+
+/// A run of text with uniform bold/italic formatting.
+pub(crate) struct StyledRun {
+    pub(crate) text: String,
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+}
+
+/// Merge neighbouring runs that share the same formatting.
+pub(crate) fn merge_runs(runs: Vec<StyledRun>) -> Vec<StyledRun> {
+    let mut merged: Vec<StyledRun> = Vec::new();
+    for run in runs {
+        match merged.last_mut() {
+            Some(last) if last.bold == run.bold && last.italic == run.italic => {
+                last.text.push_str(&run.text)
+            }
+            _ => merged.push(run),
+        }
+    }
+    merged
+}
+
+/// Whether a run property toggle such as `<w:b/>` is switched on.
+fn toggle_on(props: Option<roxmltree::Node>, name: &str) -> bool {
+    props
+        .and_then(|p| p.children().find(|c| c.has_tag_name((WORDML_NS, name))))
+        .is_some_and(|n| {
+            !matches!(
+                n.attribute((WORDML_NS, "val")),
+                Some("0") | Some("false") | Some("off")
+            )
+        })
+}
+
+/// Render runs as Markdown, merging neighbours with the same formatting and
+/// keeping edge whitespace outside emphasis markers.
+fn runs_to_markdown(runs: Vec<StyledRun>) -> String {
+    let mut out = String::new();
+    for run in merge_runs(runs) {
+        let marker = match (run.bold, run.italic) {
+            (true, true) => "***",
+            (true, false) => "**",
+            (false, true) => "*",
+            (false, false) => "",
+        };
+        let trimmed = run.text.trim();
+        if marker.is_empty() || trimmed.is_empty() {
+            out.push_str(&run.text);
+            continue;
+        }
+        let leading = &run.text[..run.text.len() - run.text.trim_start().len()];
+        let trailing = &run.text[run.text.trim_end().len()..];
+        out.push_str(leading);
+        out.push_str(marker);
+        out.push_str(trimmed);
+        out.push_str(marker);
+        out.push_str(trailing);
+    }
+    out
+}
+
+/// Whether a paragraph uses the Heading 1 style.
+fn is_heading_1(paragraph: roxmltree::Node) -> bool {
+    paragraph
+        .children()
+        .find(|c| c.has_tag_name((WORDML_NS, "pPr")))
+        .and_then(|ppr| {
+            ppr.children()
+                .find(|c| c.has_tag_name((WORDML_NS, "pStyle")))
+        })
+        .and_then(|style| style.attribute((WORDML_NS, "val")))
+        .is_some_and(|val| val.replace(' ', "").eq_ignore_ascii_case("heading1"))
+}
+
+/// Extract the formatted runs of one paragraph.
+fn paragraph_runs(paragraph: roxmltree::Node) -> Vec<StyledRun> {
+    paragraph
+        .descendants()
+        .filter(|n| n.has_tag_name((WORDML_NS, "r")))
+        .map(|run| {
+            let props = run.children().find(|c| c.has_tag_name((WORDML_NS, "rPr")));
+            let mut text = String::new();
+            for child in run.children() {
+                if child.has_tag_name((WORDML_NS, "t")) {
+                    text.push_str(child.text().unwrap_or(""));
+                } else if child.has_tag_name((WORDML_NS, "tab")) {
+                    text.push('\t');
+                } else if child.has_tag_name((WORDML_NS, "br")) {
+                    text.push('\n');
+                }
+            }
+            StyledRun {
+                text,
+                bold: toggle_on(props, "b"),
+                italic: toggle_on(props, "i"),
+            }
+        })
+        .collect()
+}
+
+/// Split a `word/document.xml` into `(title, markdown body)` chapters on Heading 1.
+/// Content before the first heading (or in a document without headings)
+/// becomes a chapter with no title.
+fn docx_chapters(xml: &str) -> Result<Vec<(Option<String>, String)>, AppError> {
+    let doc = roxmltree::Document::parse(xml)
+        .map_err(|e| AppError::Validation(format!("Invalid DOCX document XML: {}", e)))?;
+
+    let mut chapters: Vec<(Option<String>, Vec<String>)> = Vec::new();
+    for paragraph in doc
+        .descendants()
+        .filter(|n| n.has_tag_name((WORDML_NS, "p")))
+    {
+        let runs = paragraph_runs(paragraph);
+        if is_heading_1(paragraph) {
+            let title: String = runs.iter().map(|r| r.text.as_str()).collect();
+            chapters.push((Some(title.trim().to_string()), Vec::new()));
+            continue;
+        }
+
+        let text = runs_to_markdown(runs);
+        if text.trim().is_empty() {
+            continue;
+        }
+        match chapters.last_mut() {
+            Some((_, paragraphs)) => paragraphs.push(text),
+            None => chapters.push((None, vec![text])),
+        }
+    }
+
+    Ok(chapters
+        .into_iter()
+        .map(|(title, paragraphs)| {
+            let mut body = paragraphs.join("\n\n");
+            if !body.is_empty() {
+                body.push('\n');
+            }
+            (title, body)
+        })
+        .collect())
+}
+
+/// Trim blank lines around a plain-text segment, ending it with a single newline.
+fn segment_body(text: &str) -> String {
+    let trimmed = text.trim_matches(|c: char| c == '\n' || c == '\r' || c == '\x0C');
+    if trimmed.trim().is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", trimmed)
+    }
+}
+
+/// Split plain text into `(title, body)` chapters at every line matching `pattern`.
+///
+/// The whole line containing a match becomes the title (empty for a bare form
+/// feed). Text before the first match becomes an untitled chapter.
+fn plaintext_chapters(text: &str, pattern: &Regex) -> Vec<(String, String)> {
+    let mut chapters = Vec::new();
+    let mut title = String::new();
+    let mut body_start = 0;
+
+    for m in pattern.find_iter(text) {
+        let line_start = text[..m.start()].rfind('\n').map_or(0, |i| i + 1);
+        if line_start < body_start {
+            continue; // second match on an already-consumed heading line
+        }
+        let line_end = text[m.end()..]
+            .find('\n')
+            .map_or(text.len(), |i| m.end() + i);
+
+        chapters.push((title, segment_body(&text[body_start..line_start])));
+        title = text[line_start..line_end]
+            .replace('\x0C', "")
+            .trim()
+            .to_string();
+        body_start = (line_end + 1).min(text.len());
+    }
+    chapters.push((title, segment_body(&text[body_start..])));
+
+    chapters
+        .into_iter()
+        .filter(|(title, body)| !title.is_empty() || !body.is_empty())
+        .collect()
+}
+
+// ── Commands ──────────────────────────────────────────────────────
+
+/// Import a `.docx` file as manuscript chapters.
+///
+/// Each Heading 1 paragraph starts a new chapter titled after the heading;
+/// other paragraphs become Markdown with bold/italic runs preserved. A
+/// document without headings imports as a single chapter. Repeated or
+/// existing titles get suffixed slugs. Returns the slugs of the created
+/// chapters in order.
+#[tauri::command]
+pub fn import_docx(project_path: String, bytes: Vec<u8>) -> Result<Vec<String>, AppError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| AppError::Validation(format!("Invalid DOCX file: {}", e)))?;
+    let mut xml = String::new();
+    archive
+        .by_name("word/document.xml")
+        .map_err(|e| AppError::Validation(format!("Invalid DOCX file: {}", e)))?
+        .read_to_string(&mut xml)?;
+
+    let chapters = docx_chapters(&xml)?
+        .into_iter()
+        .enumerate()
+        .map(|(i, (title, body))| {
+            let title = title
+                .filter(|t| !t.is_empty())
+                .unwrap_or_else(|| format!("Untitled {}", i + 1));
+            (title, body)
+        })
+        .collect();
+    create_imported_chapters(&project_path, chapters)
+}
+
+/// Import pasted plain text as manuscript chapters.
+///
+/// Splits on `split_pattern` (a regex), defaulting to lines starting with
+/// `Chapter N` or a form feed. Each heading line becomes a chapter title;
+/// segments without one are titled "Untitled N". Repeated or existing titles
+/// get suffixed slugs. Returns the created slugs.
+#[tauri::command]
+pub fn import_plaintext(
+    project_path: String,
+    text: String,
+    split_pattern: Option<String>,
+) -> Result<Vec<String>, AppError> {
+    let pattern_str = split_pattern.as_deref().unwrap_or(DEFAULT_SPLIT_PATTERN);
+    let pattern = Regex::new(pattern_str).map_err(|e| {
+        AppError::Validation(format!("Invalid split pattern '{}': {}", pattern_str, e))
+    })?;
+
+    let chapters = plaintext_chapters(&text, &pattern)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (title, body))| {
+            let title = if title.is_empty() {
+                format!("Untitled {}", i + 1)
+            } else {
+                title
+            };
+            (title, body)
+        })
+        .collect();
+    create_imported_chapters(&project_path, chapters)
+}
+
+// ── Tests ─────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::manuscript::{create_chapter, get_chapter};
+    use crate::test_helpers::setup_test_dir;
+    use std::io::Write;
+
+    /// Helper: wrap body XML in a minimal `.docx` archive.
+    fn build_docx(body_xml: &str) -> Vec<u8> {
+        let document = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="{}"><w:body>{}</w:body></w:document>"#,
+            WORDML_NS, body_xml
+        );
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file(
+                "word/document.xml",
+                zip::write::SimpleFileOptions::default(),
+            )
+            .unwrap();
+        writer.write_all(document.as_bytes()).unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn heading(text: &str) -> String {
+        format!(
+            r#"<w:p><w:pPr><w:pStyle w:val="Heading1"/></w:pPr><w:r><w:t>{}</w:t></w:r></w:p>"#,
+            text
+        )
+    }
+
+    fn para(runs: &str) -> String {
+        format!("<w:p>{}</w:p>", runs)
+    }
+
+    fn run(text: &str) -> String {
+        format!(r#"<w:r><w:t xml:space="preserve">{}</w:t></w:r>"#, text)
+    }
+
+    // ── import_docx ───────────────────────────────────────────────
+
+    #[test]
+    fn import_docx_splits_on_heading_1() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let body = [
+            heading("The Arrival"),
+            para(&format!(
+                "{}{}{}",
+                run("She came "),
+                r#"<w:r><w:rPr><w:b/></w:rPr><w:t>late</w:t></w:r>"#,
+                run(".")
+            )),
+            para(&run("Nobody noticed.")),
+            heading("The Departure"),
+            para(r#"<w:r><w:rPr><w:i/></w:rPr><w:t>Gone</w:t></w:r>"#),
+        ]
+        .concat();
+
+        let slugs = import_docx(pp.clone(), build_docx(&body)).unwrap();
+        assert_eq!(slugs, vec!["the-arrival", "the-departure"]);
+
+        let first = get_chapter(pp.clone(), "the-arrival".to_string()).unwrap();
+        assert_eq!(first.frontmatter.title, "The Arrival");
+        assert_eq!(first.body, "She came **late**.\n\nNobody noticed.\n");
+
+        let second = get_chapter(pp.clone(), "the-departure".to_string()).unwrap();
+        assert_eq!(second.body, "*Gone*\n");
+
+        let config = get_manuscript_config(pp).unwrap();
+        assert_eq!(config.chapters, slugs);
+    }
+
+    #[test]
+    fn import_docx_without_headings_is_one_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let body = [para(&run("First paragraph.")), para(&run("Second."))].concat();
+
+        let slugs = import_docx(pp.clone(), build_docx(&body)).unwrap();
+        assert_eq!(slugs, vec!["untitled-1"]);
+
+        let chapter = get_chapter(pp, "untitled-1".to_string()).unwrap();
+        assert_eq!(chapter.body, "First paragraph.\n\nSecond.\n");
+    }
+
+    #[test]
+    fn import_docx_duplicate_headings_get_unique_slugs() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_chapter(pp.clone(), "Interlude".to_string()).unwrap();
+
+        let body = [
+            heading("Interlude"),
+            para(&run("One.")),
+            heading("Interlude"),
+            para(&run("Two.")),
+        ]
+        .concat();
+
+        let slugs = import_docx(pp.clone(), build_docx(&body)).unwrap();
+        assert_eq!(slugs, vec!["interlude-2", "interlude-3"]);
+
+        let config = get_manuscript_config(pp.clone()).unwrap();
+        assert_eq!(
+            config.chapters,
+            vec!["interlude", "interlude-2", "interlude-3"]
+        );
+        let second = get_chapter(pp, "interlude-3".to_string()).unwrap();
+        assert_eq!(second.frontmatter.title, "Interlude");
+        assert_eq!(second.frontmatter.order, 2);
+        assert_eq!(second.body, "Two.\n");
+    }
+
+    #[test]
+    fn import_docx_rejects_non_zip_bytes() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let result = import_docx(pp, b"not a docx".to_vec());
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn runs_to_markdown_merges_and_keeps_spaces_outside_markers() {
+        let runs = vec![
+            StyledRun {
+                text: "very ".to_string(),
+                bold: true,
+                italic: false,
+            },
+            StyledRun {
+                text: "bold ".to_string(),
+                bold: true,
+                italic: false,
+            },
+            StyledRun {
+                text: "and both".to_string(),
+                bold: true,
+                italic: true,
+            },
+        ];
+        assert_eq!(runs_to_markdown(runs), "**very bold** ***and both***");
+    }
+
+    #[test]
+    fn toggle_with_false_value_is_off() {
+        let xml = format!(
+            r#"<w:rPr xmlns:w="{}"><w:b w:val="0"/><w:i/></w:rPr>"#,
+            WORDML_NS
+        );
+        let doc = roxmltree::Document::parse(&xml).unwrap();
+        let props = Some(doc.root_element());
+        assert!(!toggle_on(props, "b"));
+        assert!(toggle_on(props, "i"));
+    }
+
+    // ── import_plaintext ──────────────────────────────────────────
+
+    #[test]
+    fn import_plaintext_splits_on_default_pattern() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let text = "Chapter 1: The Road\n\nThey walked.\n\nChapter 2\nThey rested.\n";
+        let slugs = import_plaintext(pp.clone(), text.to_string(), None).unwrap();
+        assert_eq!(slugs, vec!["chapter-1-the-road", "chapter-2"]);
+
+        let first = get_chapter(pp.clone(), slugs[0].clone()).unwrap();
+        assert_eq!(first.frontmatter.title, "Chapter 1: The Road");
+        assert_eq!(first.body, "They walked.\n");
+
+        let second = get_chapter(pp, slugs[1].clone()).unwrap();
+        assert_eq!(second.body, "They rested.\n");
+    }
+
+    #[test]
+    fn import_plaintext_splits_on_form_feed() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let text = "Opening words.\n\x0C\nClosing words.\n";
+        let slugs = import_plaintext(pp.clone(), text.to_string(), None).unwrap();
+        assert_eq!(slugs, vec!["untitled-1", "untitled-2"]);
+
+        let second = get_chapter(pp, "untitled-2".to_string()).unwrap();
+        assert_eq!(second.body, "Closing words.\n");
+    }
+
+    #[test]
+    fn import_plaintext_custom_pattern() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let text = "Preface text.\n### Part One\nAlpha.\n### Part Two\nBeta.\n";
+        let slugs =
+            import_plaintext(pp.clone(), text.to_string(), Some(r"(?m)^### ".to_string())).unwrap();
+        assert_eq!(slugs, vec!["untitled-1", "part-one", "part-two"]);
+
+        let preface = get_chapter(pp.clone(), "untitled-1".to_string()).unwrap();
+        assert_eq!(preface.body, "Preface text.\n");
+        let part_one = get_chapter(pp, "part-one".to_string()).unwrap();
+        assert_eq!(part_one.frontmatter.title, "### Part One");
+        assert_eq!(part_one.body, "Alpha.\n");
+    }
+
+    #[test]
+    fn import_plaintext_without_matches_is_one_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let text = "Just one long piece.\n\nWith two paragraphs.\n";
+        let slugs = import_plaintext(pp.clone(), text.to_string(), None).unwrap();
+        assert_eq!(slugs, vec!["untitled-1"]);
+
+        let chapter = get_chapter(pp, "untitled-1".to_string()).unwrap();
+        assert_eq!(
+            chapter.body,
+            "Just one long piece.\n\nWith two paragraphs.\n"
+        );
+    }
+
+    #[test]
+    fn import_plaintext_repeated_and_existing_titles_get_unique_slugs() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_chapter(pp.clone(), "Chapter 1".to_string()).unwrap();
+        create_chapter(pp.clone(), "Untitled 1".to_string()).unwrap();
+
+        let text = "Opening.\nChapter 1\nOne.\nChapter 3\nThree.\nChapter 3\nAgain.\n";
+        let slugs = import_plaintext(pp.clone(), text.to_string(), None).unwrap();
+
+        assert_eq!(
+            slugs,
+            vec!["untitled-1-2", "chapter-1-2", "chapter-3", "chapter-3-2"]
+        );
+        let config = get_manuscript_config(pp.clone()).unwrap();
+        assert_eq!(config.chapters.len(), 6);
+        let again = get_chapter(pp, "chapter-3-2".to_string()).unwrap();
+        assert_eq!(again.frontmatter.title, "Chapter 3");
+        assert_eq!(again.body, "Again.\n");
+    }
+
+    #[test]
+    fn import_plaintext_invalid_pattern_returns_error() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let result = import_plaintext(pp, "text".to_string(), Some("(".to_string()));
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+}
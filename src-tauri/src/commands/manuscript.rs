@@ -1,8 +1,12 @@
 use std::path::PathBuf;
 
+use crate::commands::compile::count_words;
+use crate::commands::search::refresh_backlink_index;
+use crate::commands::sessions::record_chapter_save;
 use crate::error::AppError;
 use crate::models::manuscript::{
-    Chapter, ChapterContent, ChapterFrontmatter, ChapterStatus, ManuscriptConfig,
+    Chapter, ChapterContent, ChapterFrontmatter, ChapterProgress, ChapterStatus,
+    ChapterStatusUpdate, ManuscriptConfig, ManuscriptProgress,
 };
 use crate::services::frontmatter;
 use crate::services::slug_service::slugify;
@@ -23,6 +27,25 @@ fn chapter_path(project_path: &str, slug: &str) -> PathBuf {
     manuscript_dir(project_path).join(format!("{}.md", slug))
 }
 
+/// Slugs of every chapter file in the manuscript directory, listed or not.
+pub(crate) fn chapter_file_slugs(project_path: &str) -> Result<Vec<String>, AppError> {
+    let dir = manuscript_dir(project_path);
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut slugs = Vec::new();
+    for entry in std::fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) == Some("md") {
+            if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                slugs.push(stem.to_string());
+            }
+        }
+    }
+    Ok(slugs)
+}
+
 /// Read the manuscript config, returning an empty config if the file doesn't exist.
 #[tauri::command]
 pub fn get_manuscript_config(project_path: String) -> Result<ManuscriptConfig, AppError> {
@@ -81,6 +104,21 @@ pub fn save_chapter(
     let dir = manuscript_dir(&project_path);
     std::fs::create_dir_all(&dir)?;
 
+    let path = chapter_path(&project_path, &slug);
+    if path.exists() {
+        let existing = std::fs::read_to_string(&path)?;
+        // A file that no longer parses is simply overwritten.
+        let current = frontmatter::parse::<ChapterFrontmatter>(&existing).ok();
+        let from = current.map_or(chapter.status.clone(), |doc| doc.frontmatter.status);
+        if !from.can_transition_to(&chapter.status) {
+            return Err(AppError::InvalidOperation(format!(
+                "Cannot change chapter status from '{}' to '{}'",
+                from.as_str(),
+                chapter.status.as_str()
+            )));
+        }
+    }
+
     let fm = ChapterFrontmatter {
         title: chapter.title,
         slug: chapter.slug,
@@ -91,9 +129,10 @@ pub fn save_chapter(
         order: chapter.order,
     };
 
-    let path = chapter_path(&project_path, &slug);
     let content = frontmatter::serialize(&fm, &body)?;
     std::fs::write(&path, content)?;
+    refresh_backlink_index(&PathBuf::from(&project_path), &path);
+    record_chapter_save(&project_path, &slug, count_words(&body) as u32)?;
     Ok(())
 }
 
@@ -260,6 +299,96 @@ pub fn reorder_chapters(project_path: String, chapter_slugs: Vec<String>) -> Res
     Ok(())
 }
 
+/// Set the status of several chapters in one batch.
+///
+/// Unknown slugs are skipped and reported. Every transition is checked before
+/// anything is written, so one disallowed change leaves all chapters untouched.
+#[tauri::command]
+pub fn set_chapters_status(
+    project_path: String,
+    slugs: Vec<String>,
+    status: ChapterStatus,
+) -> Result<ChapterStatusUpdate, AppError> {
+    let mut chapters = Vec::new();
+    let mut not_found = Vec::new();
+    for slug in slugs {
+        if !chapter_path(&project_path, &slug).exists() {
+            not_found.push(slug);
+            continue;
+        }
+        let chapter = get_chapter(project_path.clone(), slug.clone())?;
+        if !chapter.frontmatter.status.can_transition_to(&status) {
+            return Err(AppError::InvalidOperation(format!(
+                "Cannot change status of '{}' from '{}' to '{}'",
+                slug,
+                chapter.frontmatter.status.as_str(),
+                status.as_str()
+            )));
+        }
+        chapters.push(chapter);
+    }
+
+    let mut updated = Vec::new();
+    for mut chapter in chapters {
+        chapter.frontmatter.status = status.clone();
+        save_chapter(
+            project_path.clone(),
+            chapter.slug.clone(),
+            chapter.frontmatter,
+            chapter.body,
+        )?;
+        updated.push(chapter.slug);
+    }
+
+    Ok(ChapterStatusUpdate { updated, not_found })
+}
+
+/// Roll up per-chapter word counts against their targets.
+///
+/// Chapters are taken in manuscript order; missing chapter files are skipped.
+/// Chapters without a target count toward `total_words` only. Percentages are
+/// not clamped, so a chapter past its target reports more than 100.
+#[tauri::command]
+pub fn manuscript_progress(project_path: String) -> Result<ManuscriptProgress, AppError> {
+    let config = get_manuscript_config(project_path.clone())?;
+    let percent = |words: u64, target: u64| (words as f64 / target as f64) * 100.0;
+
+    let mut chapters = Vec::new();
+    for slug in &config.chapters {
+        let path = chapter_path(&project_path, slug);
+        if !path.exists() {
+            continue;
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let doc: frontmatter::ParsedDocument<ChapterFrontmatter> = frontmatter::parse(&content)?;
+
+        let words = count_words(&doc.body) as u64;
+        let target_words = doc.frontmatter.target_words.filter(|t| *t > 0);
+        chapters.push(ChapterProgress {
+            slug: slug.clone(),
+            title: doc.frontmatter.title,
+            words,
+            target_words,
+            percent_complete: target_words.map(|t| percent(words, t as u64)),
+        });
+    }
+
+    let total_words = chapters.iter().map(|c| c.words).sum();
+    let total_target_words: u64 = chapters
+        .iter()
+        .filter_map(|c| c.target_words)
+        .map(u64::from)
+        .sum();
+
+    Ok(ManuscriptProgress {
+        total_words,
+        total_target_words,
+        percent_complete: (total_target_words > 0)
+            .then(|| percent(total_words, total_target_words)),
+        chapters,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -590,6 +719,73 @@ mod tests {
         }
     }
 
+    /// Helper: a chapter summary for `slug` with the given status.
+    fn chapter_with_status(slug: &str, status: ChapterStatus) -> Chapter {
+        Chapter {
+            slug: slug.to_string(),
+            title: "Status".to_string(),
+            status,
+            pov: None,
+            synopsis: None,
+            target_words: None,
+            order: 0,
+        }
+    }
+
+    #[test]
+    fn save_chapter_allows_legal_status_transition() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let outline = chapter_with_status("status", ChapterStatus::Outline);
+        save_chapter(pp.clone(), "status".to_string(), outline, String::new()).unwrap();
+
+        let draft = chapter_with_status("status", ChapterStatus::Draft);
+        save_chapter(pp.clone(), "status".to_string(), draft, String::new()).unwrap();
+
+        let loaded = get_chapter(pp, "status".to_string()).unwrap();
+        assert_eq!(loaded.frontmatter.status, ChapterStatus::Draft);
+    }
+
+    #[test]
+    fn save_chapter_rejects_illegal_status_transition() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let outline = chapter_with_status("status", ChapterStatus::Outline);
+        save_chapter(pp.clone(), "status".to_string(), outline, String::new()).unwrap();
+
+        let final_ = chapter_with_status("status", ChapterStatus::Final);
+        let result = save_chapter(pp.clone(), "status".to_string(), final_, String::new());
+        assert!(matches!(result, Err(AppError::InvalidOperation(_))));
+
+        let loaded = get_chapter(pp, "status".to_string()).unwrap();
+        assert_eq!(loaded.frontmatter.status, ChapterStatus::Outline);
+    }
+
+    #[test]
+    fn get_chapter_tolerates_unknown_status() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        std::fs::create_dir_all(manuscript_dir(&pp)).unwrap();
+        std::fs::write(
+            chapter_path(&pp, "legacy"),
+            "---\ntitle: Legacy\nslug: legacy\nstatus: in-review\n---\nBody\n",
+        )
+        .unwrap();
+
+        let loaded = get_chapter(pp.clone(), "legacy".to_string()).unwrap();
+        assert_eq!(
+            loaded.frontmatter.status,
+            ChapterStatus::Unknown("in-review".to_string())
+        );
+
+        // An unknown status can be replaced by a known one.
+        let revised = chapter_with_status("legacy", ChapterStatus::Revised);
+        save_chapter(pp, "legacy".to_string(), revised, "Body\n".to_string()).unwrap();
+    }
+
     #[test]
     fn save_chapter_empty_body() {
         let dir = setup_test_dir();
@@ -1075,4 +1271,132 @@ mod tests {
         let result = rename_chapter(pp, "does-not-exist".to_string(), "New Name".to_string());
         assert!(result.is_err());
     }
+
+    // ── set_chapters_status ────────────────────────────────────────
+
+    #[test]
+    fn set_chapters_status_updates_each_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        for title in ["One", "Two", "Three"] {
+            create_chapter(pp.clone(), title.to_string()).unwrap();
+        }
+        let mut one = get_chapter(pp.clone(), "one".to_string()).unwrap();
+        save_chapter(
+            pp.clone(),
+            "one".to_string(),
+            one.frontmatter.clone(),
+            "Body stays.".to_string(),
+        )
+        .unwrap();
+
+        let slugs = vec!["one".to_string(), "two".to_string(), "three".to_string()];
+        let result =
+            set_chapters_status(pp.clone(), slugs.clone(), ChapterStatus::Revised).unwrap();
+
+        assert_eq!(result.updated, slugs);
+        assert!(result.not_found.is_empty());
+        for slug in &slugs {
+            let chapter = get_chapter(pp.clone(), slug.clone()).unwrap();
+            assert_eq!(chapter.frontmatter.status, ChapterStatus::Revised);
+        }
+        one = get_chapter(pp, "one".to_string()).unwrap();
+        assert_eq!(one.body, "Body stays.");
+    }
+
+    #[test]
+    fn set_chapters_status_reports_missing_slugs() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_chapter(pp.clone(), "One".to_string()).unwrap();
+
+        let result = set_chapters_status(
+            pp.clone(),
+            vec!["missing".to_string(), "one".to_string()],
+            ChapterStatus::Final,
+        )
+        .unwrap();
+
+        assert_eq!(result.updated, vec!["one"]);
+        assert_eq!(result.not_found, vec!["missing"]);
+    }
+
+    #[test]
+    fn set_chapters_status_disallowed_transition_changes_nothing() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_chapter(pp.clone(), "One".to_string()).unwrap();
+        create_chapter(pp.clone(), "Two".to_string()).unwrap();
+        set_chapters_status(pp.clone(), vec!["two".to_string()], ChapterStatus::Final).unwrap();
+
+        let result = set_chapters_status(
+            pp.clone(),
+            vec!["one".to_string(), "two".to_string()],
+            ChapterStatus::Outline,
+        );
+
+        assert!(matches!(result, Err(AppError::InvalidOperation(_))));
+        let one = get_chapter(pp.clone(), "one".to_string()).unwrap();
+        assert_eq!(one.frontmatter.status, ChapterStatus::Draft);
+        let two = get_chapter(pp, "two".to_string()).unwrap();
+        assert_eq!(two.frontmatter.status, ChapterStatus::Final);
+    }
+
+    // ── manuscript_progress ────────────────────────────────────────
+
+    /// Helper: create a chapter and give it a body of `words` words and an optional target.
+    fn chapter_with_words(pp: &str, title: &str, words: usize, target: Option<u32>) {
+        let created = create_chapter(pp.to_string(), title.to_string()).unwrap();
+        let mut chapter = created.frontmatter;
+        chapter.target_words = target;
+        let body = vec!["word"; words].join(" ");
+        save_chapter(pp.to_string(), created.slug, chapter, body).unwrap();
+    }
+
+    #[test]
+    fn manuscript_progress_rolls_up_targets() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        chapter_with_words(&pp, "One", 500, Some(1000));
+        chapter_with_words(&pp, "Two", 1200, Some(1000));
+        chapter_with_words(&pp, "Three", 300, None);
+
+        let progress = manuscript_progress(pp).unwrap();
+
+        assert_eq!(progress.total_words, 2000);
+        assert_eq!(progress.total_target_words, 2000);
+        assert_eq!(progress.percent_complete, Some(100.0));
+
+        let slugs: Vec<&str> = progress.chapters.iter().map(|c| c.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["one", "two", "three"]);
+        assert_eq!(progress.chapters[0].percent_complete, Some(50.0));
+        assert_eq!(progress.chapters[1].percent_complete, Some(120.0));
+        assert_eq!(progress.chapters[2].words, 300);
+        assert_eq!(progress.chapters[2].target_words, None);
+        assert_eq!(progress.chapters[2].percent_complete, None);
+    }
+
+    #[test]
+    fn manuscript_progress_without_targets_has_no_percent() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        chapter_with_words(&pp, "One", 10, None);
+
+        let progress = manuscript_progress(pp).unwrap();
+        assert_eq!(progress.total_words, 10);
+        assert_eq!(progress.total_target_words, 0);
+        assert_eq!(progress.percent_complete, None);
+    }
+
+    #[test]
+    fn manuscript_progress_empty_project() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let progress = manuscript_progress(pp).unwrap();
+        assert_eq!(progress.total_words, 0);
+        assert!(progress.chapters.is_empty());
+    }
 }
@@ -1,7 +1,10 @@
 pub mod compile;
 pub mod entity;
+pub mod export;
+pub mod import;
 pub mod manuscript;
 pub mod notes;
 pub mod project;
 pub mod search;
 pub mod sessions;
+pub mod stats;
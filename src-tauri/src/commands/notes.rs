@@ -1,7 +1,8 @@
 use std::path::PathBuf;
 
+use crate::commands::search::refresh_backlink_index;
 use crate::error::AppError;
-use crate::models::notes::{NoteContent, NoteEntry, NoteFrontmatter, NotesConfig};
+use crate::models::notes::{NoteColor, NoteContent, NoteEntry, NoteFrontmatter, NotesConfig};
 use crate::services::frontmatter;
 use crate::services::slug_service::slugify;
 use crate::services::yaml_service::{read_yaml, write_yaml};
@@ -21,19 +22,86 @@ fn note_path(project_path: &str, slug: &str) -> PathBuf {
     notes_dir(project_path).join(format!("{}.md", slug))
 }
 
+/// Named card colors offered by the corkboard, as `(name, hex)` pairs.
+const NOTE_PALETTE: &[(&str, &str)] = &[
+    ("red", "#ef4444"),
+    ("amber", "#f59e0b"),
+    ("green", "#22c55e"),
+    ("blue", "#3b82f6"),
+    ("violet", "#8b5cf6"),
+    ("pink", "#ec4899"),
+    ("gray", "#6b7280"),
+];
+
+/// Check that a note color is `#rgb`/`#rrggbb` hex or a palette name.
+fn validate_note_color(color: &str) -> Result<(), AppError> {
+    let is_hex = color.strip_prefix('#').is_some_and(|digits| {
+        matches!(digits.len(), 3 | 6) && digits.chars().all(|c| c.is_ascii_hexdigit())
+    });
+    if is_hex || NOTE_PALETTE.iter().any(|(name, _)| *name == color) {
+        return Ok(());
+    }
+
+    let names: Vec<&str> = NOTE_PALETTE.iter().map(|(name, _)| *name).collect();
+    Err(AppError::Validation(format!(
+        "Invalid note color '{}': expected #rgb, #rrggbb or one of {}",
+        color,
+        names.join(", ")
+    )))
+}
+
+/// The preset note colors, in display order.
+#[tauri::command]
+pub fn default_note_palette() -> Vec<NoteColor> {
+    NOTE_PALETTE
+        .iter()
+        .map(|(name, hex)| NoteColor {
+            name: name.to_string(),
+            hex: hex.to_string(),
+        })
+        .collect()
+}
+
 /// Read the notes config, returning an empty config if the file doesn't exist.
+///
+/// Card colors that aren't valid (hand edits, older versions) are dropped, so
+/// they never reach a card's style and the config can always be saved back.
 #[tauri::command]
 pub fn get_notes_config(project_path: String) -> Result<NotesConfig, AppError> {
     let path = config_path(&project_path);
     if !path.exists() {
         return Ok(NotesConfig { notes: vec![] });
     }
-    read_yaml(&path)
+    let mut config: NotesConfig = read_yaml(&path)?;
+    for entry in &mut config.notes {
+        if entry
+            .color
+            .as_deref()
+            .is_some_and(|color| validate_note_color(color).is_err())
+        {
+            entry.color = None;
+        }
+    }
+    Ok(config)
 }
 
 /// Write the notes config to disk, creating the directory if needed.
+///
+/// Every card color is validated, and the whole config is rejected if any of
+/// them is invalid.
 #[tauri::command]
 pub fn save_notes_config(project_path: String, config: NotesConfig) -> Result<(), AppError> {
+    for entry in &config.notes {
+        if let Some(color) = &entry.color {
+            validate_note_color(color).map_err(|e| match e {
+                AppError::Validation(msg) => {
+                    AppError::Validation(format!("Note '{}': {}", entry.slug, msg))
+                }
+                other => other,
+            })?;
+        }
+    }
+
     let path = config_path(&project_path);
     write_yaml(&path, &config)
 }
@@ -76,6 +144,7 @@ pub fn save_note(
     let path = note_path(&project_path, &slug);
     let content = frontmatter::serialize(&fm, &body)?;
     std::fs::write(&path, content)?;
+    refresh_backlink_index(&PathBuf::from(&project_path), &path);
     Ok(())
 }
 
@@ -209,6 +278,31 @@ pub fn rename_note(
     })
 }
 
+/// Move a note to `new_index` in the notes config order.
+///
+/// The index refers to the position after removal, so `0` moves the note to
+/// the front; an index past the end moves it to the end.
+#[tauri::command]
+pub fn reorder_note(
+    project_path: String,
+    slug: String,
+    new_index: usize,
+) -> Result<NotesConfig, AppError> {
+    let mut config = get_notes_config(project_path.clone())?;
+    let current = config
+        .notes
+        .iter()
+        .position(|n| n.slug == slug)
+        .ok_or_else(|| AppError::NotFound(format!("Note not found in config: {}", slug)))?;
+
+    let entry = config.notes.remove(current);
+    let index = new_index.min(config.notes.len());
+    config.notes.insert(index, entry);
+
+    save_notes_config(project_path, config.clone())?;
+    Ok(config)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1039,4 +1133,169 @@ mod tests {
         assert_eq!(entry.color, Some("blue".to_string()));
         assert_eq!(entry.label, Some("important".to_string()));
     }
+
+    // ── reorder_note ──────────────────────────────────────────────
+
+    fn config_slugs(pp: &str) -> Vec<String> {
+        get_notes_config(pp.to_string())
+            .unwrap()
+            .notes
+            .into_iter()
+            .map(|n| n.slug)
+            .collect()
+    }
+
+    fn create_three_notes(pp: &str) {
+        for title in ["Alpha", "Beta", "Gamma"] {
+            create_note(pp.to_string(), title.to_string()).unwrap();
+        }
+    }
+
+    #[test]
+    fn reorder_note_moves_to_middle() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_three_notes(&pp);
+
+        let config = reorder_note(pp.clone(), "alpha".to_string(), 1).unwrap();
+        let returned: Vec<&str> = config.notes.iter().map(|n| n.slug.as_str()).collect();
+        assert_eq!(returned, vec!["beta", "alpha", "gamma"]);
+        assert_eq!(config_slugs(&pp), vec!["beta", "alpha", "gamma"]);
+    }
+
+    #[test]
+    fn reorder_note_moves_to_front() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_three_notes(&pp);
+
+        reorder_note(pp.clone(), "gamma".to_string(), 0).unwrap();
+        assert_eq!(config_slugs(&pp), vec!["gamma", "alpha", "beta"]);
+    }
+
+    #[test]
+    fn reorder_note_past_end_moves_to_end() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_three_notes(&pp);
+
+        reorder_note(pp.clone(), "alpha".to_string(), 99).unwrap();
+        assert_eq!(config_slugs(&pp), vec!["beta", "gamma", "alpha"]);
+    }
+
+    #[test]
+    fn reorder_note_keeps_entry_metadata() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_three_notes(&pp);
+
+        let mut config = get_notes_config(pp.clone()).unwrap();
+        config.notes[0].color = Some("red".to_string());
+        save_notes_config(pp.clone(), config).unwrap();
+
+        reorder_note(pp.clone(), "alpha".to_string(), 2).unwrap();
+        let config = get_notes_config(pp).unwrap();
+        assert_eq!(config.notes[2].slug, "alpha");
+        assert_eq!(config.notes[2].color, Some("red".to_string()));
+    }
+
+    #[test]
+    fn reorder_note_unknown_slug_returns_not_found() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_three_notes(&pp);
+
+        let result = reorder_note(pp.clone(), "missing".to_string(), 0);
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+        assert_eq!(config_slugs(&pp), vec!["alpha", "beta", "gamma"]);
+    }
+
+    // ── note colors ───────────────────────────────────────────────
+
+    fn save_with_color(pp: &str, color: &str) -> Result<(), AppError> {
+        create_note(pp.to_string(), "Alpha".to_string()).unwrap();
+        let mut config = get_notes_config(pp.to_string()).unwrap();
+        config.notes[0].color = Some(color.to_string());
+        save_notes_config(pp.to_string(), config)
+    }
+
+    #[test]
+    fn save_notes_config_accepts_hex_colors() {
+        for color in ["#abc", "#A1b2C3"] {
+            let dir = setup_test_dir();
+            let pp = dir.path().to_str().unwrap().to_string();
+            save_with_color(&pp, color).unwrap();
+            let config = get_notes_config(pp).unwrap();
+            assert_eq!(config.notes[0].color, Some(color.to_string()));
+        }
+    }
+
+    #[test]
+    fn save_notes_config_accepts_palette_name() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        save_with_color(&pp, "violet").unwrap();
+        let config = get_notes_config(pp).unwrap();
+        assert_eq!(config.notes[0].color, Some("violet".to_string()));
+    }
+
+    #[test]
+    fn get_notes_config_drops_invalid_colors() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_note(pp.clone(), "Alpha".to_string()).unwrap();
+        create_note(pp.clone(), "Beta".to_string()).unwrap();
+        let mut config = get_notes_config(pp.clone()).unwrap();
+        config.notes[0].color = Some("red;}body{display:none".to_string());
+        config.notes[1].color = Some("#22c55e".to_string());
+        write_yaml(&config_path(&pp), &config).unwrap();
+
+        let config = get_notes_config(pp).unwrap();
+        assert_eq!(config.notes[0].color, None);
+        assert_eq!(config.notes[1].color, Some("#22c55e".to_string()));
+    }
+
+    #[test]
+    fn rename_note_with_legacy_invalid_color_succeeds() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        create_note(pp.clone(), "Alpha".to_string()).unwrap();
+        let mut config = get_notes_config(pp.clone()).unwrap();
+        config.notes[0].color = Some("chartreuse".to_string());
+        write_yaml(&config_path(&pp), &config).unwrap();
+
+        rename_note(pp.clone(), "alpha".to_string(), "Omega".to_string()).unwrap();
+
+        let config = get_notes_config(pp).unwrap();
+        assert_eq!(config.notes[0].slug, "omega");
+        assert_eq!(config.notes[0].color, None);
+    }
+
+    #[test]
+    fn save_notes_config_rejects_invalid_color() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        for color in ["chartreuse", "#abcd", "#ggg", "ff0000", ""] {
+            match save_with_color(&pp, color) {
+                Err(AppError::Validation(msg)) => {
+                    assert!(msg.contains("alpha"), "{}", msg);
+                    assert!(msg.contains("#rrggbb"), "{}", msg);
+                }
+                other => panic!("expected validation error for {:?}, got {:?}", color, other),
+            }
+            delete_note(pp.clone(), "alpha".to_string()).unwrap();
+        }
+    }
+
+    #[test]
+    fn default_note_palette_lists_named_hex_colors() {
+        let palette = default_note_palette();
+        assert_eq!(palette.len(), 7);
+        assert_eq!(palette[0].name, "red");
+        assert!(palette.iter().any(|c| c.name == "blue"));
+        for color in &palette {
+            assert!(validate_note_color(&color.hex).is_ok());
+            assert!(validate_note_color(&color.name).is_ok());
+        }
+    }
 }
@@ -466,6 +466,21 @@ mod tests {
         assert_eq!(loaded.name, "Second Name");
     }
 
+    #[test]
+    fn save_project_manifest_failure_keeps_previous_manifest() {
+        let (_dir, root) = setup_test_project();
+        let path = root.to_str().unwrap().to_string();
+        let before = std::fs::read_to_string(root.join("sakya.yaml")).unwrap();
+
+        // A directory squatting on the temp path makes the write fail.
+        std::fs::create_dir(root.join(".sakya.yaml.tmp")).unwrap();
+        let result = save_project_manifest(path, ProjectManifest::new("Broken".to_string()));
+
+        assert!(result.is_err());
+        let after = std::fs::read_to_string(root.join("sakya.yaml")).unwrap();
+        assert_eq!(after, before);
+    }
+
     // ── create + open integration ───────────────────────────────────
 
     #[test]
@@ -1,9 +1,15 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
+use regex::{NoExpand, Regex};
 use serde::{Deserialize, Serialize};
 use walkdir::WalkDir;
 
+use crate::commands::entity::list_all_entities;
 use crate::error::AppError;
+use crate::models::entity::EntitySummary;
 
 // ── Models ────────────────────────────────────────────────────────
 
@@ -29,6 +35,34 @@ pub struct WikiLinkTarget {
     pub entity_type: Option<String>,
 }
 
+/// What a wiki link points at, telling chapters, notes and entities apart.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WikiTarget {
+    Chapter {
+        slug: String,
+    },
+    Note {
+        slug: String,
+    },
+    Entity {
+        schema: String,
+        slug: String,
+    },
+    /// A link whose title matches more than one file.
+    Ambiguous(Vec<WikiTarget>),
+}
+
+/// A wiki-link completion suggestion for a partially typed `[[link`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WikiLinkCandidate {
+    pub title: String,
+    pub slug: String,
+    pub file_type: String,
+    pub entity_type: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BacklinkResult {
@@ -40,6 +74,91 @@ pub struct BacklinkResult {
     pub line_number: usize,
 }
 
+/// A `[[wiki link]]` in a chapter or note that matches no file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokenLink {
+    /// `chapter` or `note`.
+    pub source_type: String,
+    pub source_slug: String,
+    /// The link target as written between the brackets.
+    pub link_text: String,
+    pub line_number: usize,
+}
+
+/// How `search_project` interprets its query.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchMode {
+    /// Case-insensitive substring match.
+    #[default]
+    Substring,
+    /// Case-insensitive match on Unicode word boundaries.
+    WholeWord,
+    /// Regular expression, matched as written.
+    Regex,
+}
+
+/// Options for `search_content`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentSearchOptions {
+    #[serde(default)]
+    pub case_insensitive: bool,
+    #[serde(default)]
+    pub whole_word: bool,
+}
+
+/// A file whose body matched a content search, with its best snippet.
+///
+/// `highlight_start`/`highlight_end` are byte offsets of the match within `snippet`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentMatch {
+    pub title: String,
+    pub slug: String,
+    pub file_type: String,
+    pub match_count: usize,
+    pub snippet: String,
+    pub highlight_start: usize,
+    pub highlight_end: usize,
+}
+
+/// A single line changed (or to be changed) by `replace_in_project`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplacementChange {
+    pub slug: String,
+    pub line_number: usize,
+    pub before: String,
+    pub after: String,
+}
+
+/// Cached map from wiki-link target to the files that link to it, stored in
+/// `.sakya/backlinks.json`.
+///
+/// Targets are lowercased link texts, kind prefix included (see
+/// `wiki_link_key`), matching the case-insensitive lookup in
+/// `find_backlinks`. Sources are paths relative to the project root.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BacklinkIndex {
+    pub links: BTreeMap<String, BTreeSet<String>>,
+    /// Modification time (ns since the epoch) of every indexed file, compared
+    /// against the files on disk to tell whether the index is stale.
+    pub files: BTreeMap<String, u64>,
+    /// Title and slug of every indexed file with parseable frontmatter, so
+    /// `find_backlinks` can tell which files a title names without reading them.
+    pub titles: BTreeMap<String, IndexedTitle>,
+}
+
+/// Title and slug of one file in the backlink index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedTitle {
+    pub title: String,
+    pub slug: String,
+}
+
 // ── Minimal frontmatter for search ────────────────────────────────
 
 /// We only need title + slug from any file's frontmatter.
@@ -99,6 +218,22 @@ fn parse_frontmatter(content: &str) -> Result<(MinimalFrontmatter, usize), AppEr
     Ok((fm, body_start_line))
 }
 
+/// Byte offset where the body starts, i.e. just past the closing `---` line.
+/// Everything before it is left untouched by replacements.
+fn body_offset(content: &str) -> Option<usize> {
+    let start = content.len() - content.trim_start().len();
+    if !content[start..].starts_with("---") {
+        return None;
+    }
+    let after_first = start + 3;
+    let closing = after_first + content[after_first..].find("\n---")? + 1;
+    Some(
+        content[closing..]
+            .find('\n')
+            .map_or(content.len(), |i| closing + i + 1),
+    )
+}
+
 /// Walk all .md files in the project's manuscript/, entities/, and notes/ directories.
 fn walk_md_files(project_path: &Path) -> Vec<PathBuf> {
     let mut files = Vec::new();
@@ -120,6 +255,197 @@ fn walk_md_files(project_path: &Path) -> Vec<PathBuf> {
     files
 }
 
+/// Path of a file relative to the project root, with `/` separators.
+fn relative_key(project_path: &Path, file_path: &Path) -> Option<String> {
+    let rel = file_path.strip_prefix(project_path).ok()?;
+    let parts: Vec<&str> = rel
+        .components()
+        .map(|c| c.as_os_str().to_str().unwrap_or(""))
+        .collect();
+    Some(parts.join("/"))
+}
+
+fn backlink_index_path(project_path: &Path) -> PathBuf {
+    project_path.join(".sakya").join("backlinks.json")
+}
+
+fn modified_nanos(path: &Path) -> Option<u64> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let since_epoch = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(since_epoch.as_nanos() as u64)
+}
+
+/// Every `[[target]]` on one line: the byte range of the whole link,
+/// brackets included, and the target as written.
+pub(crate) fn wiki_link_spans(line: &str) -> Vec<(Range<usize>, &str)> {
+    let mut spans = Vec::new();
+    let mut pos = 0;
+    while let Some(start) = line[pos..].find("[[").map(|i| pos + i) {
+        let Some(end) = line[start + 2..].find("]]").map(|i| start + 2 + i) else {
+            break;
+        };
+        spans.push((start..end + 2, &line[start + 2..end]));
+        pos = end + 2;
+    }
+    spans
+}
+
+/// The targets of every `[[target]]` on one line, as written.
+fn wiki_links_in_line(line: &str) -> Vec<&str> {
+    wiki_link_spans(line)
+        .into_iter()
+        .map(|(_, target)| target)
+        .collect()
+}
+
+/// Split a wiki link into its kind prefix and title, e.g. `character:Alice`
+/// into `(Some("character"), "Alice")`.
+///
+/// The prefix is lowercased and only split off when `is_kind` recognises it,
+/// so titles like "Part 1: Dawn" stay whole. The title is trimmed.
+pub(crate) fn split_link_kind(
+    link_text: &str,
+    is_kind: impl Fn(&str) -> bool,
+) -> (Option<String>, &str) {
+    if let Some((prefix, rest)) = link_text.split_once(':') {
+        let prefix = prefix.trim().to_lowercase();
+        if is_kind(&prefix) {
+            return (Some(prefix), rest.trim());
+        }
+    }
+    (None, link_text.trim())
+}
+
+/// The kinds a typed link can name in a project: `chapter`, `note`, and every
+/// entity schema directory.
+fn link_kinds(project_path: &Path) -> BTreeSet<String> {
+    let mut kinds: BTreeSet<String> = ["chapter", "note"].map(str::to_string).into();
+    if let Ok(entries) = std::fs::read_dir(project_path.join("entities")) {
+        kinds.extend(
+            entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().is_dir())
+                .map(|e| e.file_name().to_string_lossy().to_string()),
+        );
+    }
+    kinds
+}
+
+/// A link's lowercased title, prefixed with its kind when it names one in
+/// `kinds`, so `[[character:Alice]]` keys as `character:alice` and `[[Alice]]`
+/// as `alice`.
+fn wiki_link_key(link_text: &str, kinds: &BTreeSet<String>) -> String {
+    match split_link_kind(link_text, |k| kinds.contains(k)) {
+        (Some(kind), title) => format!("{}:{}", kind, title.to_lowercase()),
+        (None, title) => title.to_lowercase(),
+    }
+}
+
+/// The key (see `wiki_link_key`) of every `[[target]]` in `content`.
+fn extract_wiki_links(content: &str, kinds: &BTreeSet<String>) -> BTreeSet<String> {
+    content
+        .lines()
+        .flat_map(wiki_links_in_line)
+        .map(|target| wiki_link_key(target, kinds))
+        .collect()
+}
+
+/// Drop a file's outgoing links from the index, then re-add them if it still exists.
+fn reindex_file(
+    index: &mut BacklinkIndex,
+    project_path: &Path,
+    file_path: &Path,
+    kinds: &BTreeSet<String>,
+) {
+    let Some(key) = relative_key(project_path, file_path) else {
+        return;
+    };
+
+    index.files.remove(&key);
+    index.titles.remove(&key);
+    index.links.retain(|_, sources| {
+        sources.remove(&key);
+        !sources.is_empty()
+    });
+
+    let Some(modified) = modified_nanos(file_path) else {
+        return;
+    };
+    index.files.insert(key.clone(), modified);
+
+    let Ok(content) = std::fs::read_to_string(file_path) else {
+        return;
+    };
+    if classify_file(project_path, file_path).is_none() {
+        return;
+    }
+    let Ok((fm, _)) = parse_frontmatter(&content) else {
+        return;
+    };
+    index.titles.insert(
+        key.clone(),
+        IndexedTitle {
+            title: fm.title,
+            slug: fm.slug,
+        },
+    );
+    for target in extract_wiki_links(&content, kinds) {
+        index.links.entry(target).or_default().insert(key.clone());
+    }
+}
+
+fn write_backlink_index(project_path: &Path, index: &BacklinkIndex) -> Result<(), AppError> {
+    let path = backlink_index_path(project_path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(index)?)?;
+    Ok(())
+}
+
+/// Modification time of every Markdown file `walk_md_files` finds, keyed
+/// like `BacklinkIndex::files`.
+fn file_stamps(project_path: &Path) -> BTreeMap<String, u64> {
+    walk_md_files(project_path)
+        .iter()
+        .filter_map(|path| Some((relative_key(project_path, path)?, modified_nanos(path)?)))
+        .collect()
+}
+
+fn read_backlink_index(project_path: &Path) -> Option<BacklinkIndex> {
+    let content = std::fs::read_to_string(backlink_index_path(project_path)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// The cached index, rebuilt first if any file was added, removed or
+/// modified since it was written. `None` when no index exists or it cannot be
+/// rebuilt.
+fn load_fresh_backlink_index(project_path: &Path) -> Option<BacklinkIndex> {
+    let index = read_backlink_index(project_path)?;
+    if index.files == file_stamps(project_path) {
+        return Some(index);
+    }
+    build_backlink_index(project_path.to_string_lossy().to_string()).ok()
+}
+
+/// Re-read one saved file into the backlink index, if the project has one.
+///
+/// Called after a Markdown file is written, so the index stays fresh and
+/// `find_backlinks` doesn't rebuild it on the next lookup. Failures are
+/// ignored; the file itself has already been saved.
+pub(crate) fn refresh_backlink_index(project_path: &Path, file_path: &Path) {
+    let Some(mut index) = read_backlink_index(project_path) else {
+        return;
+    };
+    reindex_file(
+        &mut index,
+        project_path,
+        file_path,
+        &link_kinds(project_path),
+    );
+    let _ = write_backlink_index(project_path, &index);
+}
+
 /// File-type priority for search result sorting (lower = higher priority).
 fn file_type_priority(file_type: &str) -> u8 {
     match file_type {
@@ -130,6 +456,161 @@ fn file_type_priority(file_type: &str) -> u8 {
     }
 }
 
+/// A compiled `search_project` / `replace_in_project` query.
+///
+/// `WholeWord` queries compile to a plain substring regex whose matches are
+/// then kept only when `is_whole_word` accepts them, so queries that start or
+/// end with punctuation ("Mr.", "(aside)") behave like `search_content`.
+struct QueryMatcher {
+    regex: Regex,
+    whole_word: bool,
+}
+
+impl QueryMatcher {
+    /// Byte ranges of all non-overlapping matches in `text`.
+    fn match_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        if !self.whole_word {
+            return self.regex.find_iter(text).map(|m| m.range()).collect();
+        }
+
+        let mut ranges = Vec::new();
+        let mut pos = 0;
+        while let Some(m) = self.regex.find_at(text, pos) {
+            if is_whole_word(text, m.start(), m.end()) {
+                ranges.push(m.range());
+                pos = m.end();
+            } else {
+                // Retry one character later: a rejected match may hide an
+                // overlapping one that does sit on word boundaries.
+                pos = m.start() + text[m.start()..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        ranges
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        if self.whole_word {
+            !self.match_ranges(text).is_empty()
+        } else {
+            self.regex.is_match(text)
+        }
+    }
+
+    /// Replace every match in `text`. With `expand`, `replacement` may
+    /// reference capture groups; otherwise it is inserted literally.
+    fn replace_all<'t>(&self, text: &'t str, replacement: &str, expand: bool) -> Cow<'t, str> {
+        if !self.whole_word {
+            return if expand {
+                self.regex.replace_all(text, replacement)
+            } else {
+                self.regex.replace_all(text, NoExpand(replacement))
+            };
+        }
+
+        let ranges = self.match_ranges(text);
+        if ranges.is_empty() {
+            return Cow::Borrowed(text);
+        }
+        let mut out = String::with_capacity(text.len());
+        let mut last = 0;
+        for range in ranges {
+            out.push_str(&text[last..range.start]);
+            out.push_str(replacement);
+            last = range.end;
+        }
+        out.push_str(&text[last..]);
+        Cow::Owned(out)
+    }
+}
+
+/// Compile a search query according to `mode`.
+///
+/// Returns a Validation error (never panics) when a `Regex` query is malformed.
+fn compile_query(query: &str, mode: SearchMode) -> Result<QueryMatcher, AppError> {
+    let pattern = match mode {
+        SearchMode::Substring | SearchMode::WholeWord => format!("(?i){}", regex::escape(query)),
+        SearchMode::Regex => query.to_string(),
+    };
+    let regex = Regex::new(&pattern)
+        .map_err(|e| AppError::Validation(format!("Invalid regex pattern '{}': {}", query, e)))?;
+    Ok(QueryMatcher {
+        regex,
+        whole_word: mode == SearchMode::WholeWord,
+    })
+}
+
+/// Number of characters of context kept on each side of a content match.
+const SNIPPET_CONTEXT_CHARS: usize = 40;
+
+/// Lowercase `text`, returning the folded string plus, for every byte of it,
+/// the byte offset of the originating character in `text` (with a trailing
+/// entry for `text.len()`). Lowercasing can change byte lengths, so match
+/// offsets in the folded string must be mapped back through this table.
+fn fold_case(text: &str) -> (String, Vec<usize>) {
+    let mut folded = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    for (i, ch) in text.char_indices() {
+        for lower in ch.to_lowercase() {
+            folded.push(lower);
+            offsets.extend(std::iter::repeat_n(i, lower.len_utf8()));
+        }
+    }
+    offsets.push(text.len());
+    (folded, offsets)
+}
+
+/// Whether the match `start..end` in `text` sits on word boundaries.
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+}
+
+/// Find all non-overlapping occurrences of `query` in `text`, as byte ranges into `text`.
+fn find_content_matches(
+    text: &str,
+    query: &str,
+    options: &ContentSearchOptions,
+) -> Vec<(usize, usize)> {
+    let (haystack, needle, offsets) = if options.case_insensitive {
+        let (folded, offsets) = fold_case(text);
+        (folded, query.to_lowercase(), Some(offsets))
+    } else {
+        (text.to_string(), query.to_string(), None)
+    };
+
+    haystack
+        .match_indices(&needle)
+        .map(|(start, m)| {
+            let end = start + m.len();
+            match &offsets {
+                Some(offsets) => (offsets[start], offsets[end]),
+                None => (start, end),
+            }
+        })
+        .filter(|&(start, end)| !options.whole_word || is_whole_word(text, start, end))
+        .collect()
+}
+
+/// Cut a snippet of roughly `2 * SNIPPET_CONTEXT_CHARS` characters around the
+/// match `start..end`. Newlines are flattened to spaces. Returns the snippet and
+/// the match's byte range within it.
+fn build_snippet(text: &str, start: usize, end: usize) -> (String, usize, usize) {
+    let snippet_start = text[..start]
+        .char_indices()
+        .rev()
+        .take(SNIPPET_CONTEXT_CHARS)
+        .last()
+        .map_or(start, |(i, _)| i);
+    let snippet_end = text[end..]
+        .char_indices()
+        .nth(SNIPPET_CONTEXT_CHARS)
+        .map_or(text.len(), |(i, _)| end + i);
+
+    let snippet = text[snippet_start..snippet_end].replace(['\n', '\r'], " ");
+    (snippet, start - snippet_start, end - snippet_start)
+}
+
 // ── Commands ──────────────────────────────────────────────────────
 
 /// Full-text search across all project .md files.
@@ -139,12 +620,18 @@ fn file_type_priority(file_type: &str) -> u8 {
 /// - Then by file_type priority (chapter > entity > note)
 ///
 /// The frontmatter section is skipped for body search, but the title field is searched.
+/// `mode` defaults to `SearchMode::Substring` when omitted.
 #[tauri::command]
-pub fn search_project(project_path: String, query: String) -> Result<Vec<SearchResult>, AppError> {
+pub fn search_project(
+    project_path: String,
+    query: String,
+    mode: Option<SearchMode>,
+) -> Result<Vec<SearchResult>, AppError> {
     if query.is_empty() {
         return Ok(Vec::new());
     }
 
+    let matcher = compile_query(&query, mode.unwrap_or_default())?;
     let project = PathBuf::from(&project_path);
     let query_lower = query.to_lowercase();
     let mut results: Vec<(bool, SearchResult)> = Vec::new();
@@ -165,7 +652,7 @@ pub fn search_project(project_path: String, query: String) -> Result<Vec<SearchR
             Err(_) => continue,
         };
 
-        let title_matches = fm.title.to_lowercase().contains(&query_lower);
+        let title_matches = matcher.is_match(&fm.title);
         let is_exact_title = fm.title.to_lowercase() == query_lower;
 
         let lines: Vec<&str> = content.lines().collect();
@@ -177,9 +664,7 @@ pub fn search_project(project_path: String, query: String) -> Result<Vec<SearchR
                 if i >= body_start_line {
                     break;
                 }
-                if line.to_lowercase().contains("title:")
-                    && line.to_lowercase().contains(&query_lower)
-                {
+                if line.to_lowercase().contains("title:") && matcher.is_match(line) {
                     let context_before = if i > 0 {
                         lines[i - 1].to_string()
                     } else {
@@ -213,7 +698,7 @@ pub fn search_project(project_path: String, query: String) -> Result<Vec<SearchR
             if i < body_start_line {
                 continue;
             }
-            if line.to_lowercase().contains(&query_lower) {
+            if matcher.is_match(line) {
                 let context_before = if i > 0 {
                     lines[i - 1].to_string()
                 } else {
@@ -254,17 +739,338 @@ pub fn search_project(project_path: String, query: String) -> Result<Vec<SearchR
     Ok(capped)
 }
 
+/// Search the bodies of manuscript chapters and notes for `query`.
+///
+/// Returns one result per matching file, ranked by match count (then by
+/// file_type priority). Each result carries a snippet around the first match.
+/// Frontmatter is never searched.
+#[tauri::command]
+pub fn search_content(
+    project_path: String,
+    query: String,
+    options: ContentSearchOptions,
+) -> Result<Vec<ContentMatch>, AppError> {
+    if query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let project = PathBuf::from(&project_path);
+    let mut results: Vec<ContentMatch> = Vec::new();
+
+    for file_path in walk_md_files(&project) {
+        let file_type = match classify_file(&project, &file_path) {
+            Some((file_type, _)) if file_type != "entity" => file_type,
+            _ => continue,
+        };
+
+        let content = match std::fs::read_to_string(&file_path) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        let (fm, body_start_line) = match parse_frontmatter(&content) {
+            Ok(r) => r,
+            Err(_) => continue,
+        };
+
+        let body = content
+            .lines()
+            .skip(body_start_line)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let matches = find_content_matches(&body, &query, &options);
+        let Some(&(start, end)) = matches.first() else {
+            continue;
+        };
+
+        let (snippet, highlight_start, highlight_end) = build_snippet(&body, start, end);
+        results.push(ContentMatch {
+            title: fm.title,
+            slug: fm.slug,
+            file_type,
+            match_count: matches.len(),
+            snippet,
+            highlight_start,
+            highlight_end,
+        });
+    }
+
+    results.sort_by(|a, b| {
+        b.match_count
+            .cmp(&a.match_count)
+            .then_with(|| file_type_priority(&a.file_type).cmp(&file_type_priority(&b.file_type)))
+            .then_with(|| a.slug.cmp(&b.slug))
+    });
+
+    Ok(results)
+}
+
+/// Replace matches of `find` in manuscript chapter bodies.
+///
+/// Matching is line-by-line using the same `SearchMode` rules as `search_project`.
+/// In `Regex` mode, `replace` may reference capture groups (`$1`, `${name}`);
+/// otherwise it is inserted literally. Frontmatter is never modified.
+///
+/// With `dry_run` set, returns the changes that would be made without writing.
+#[tauri::command]
+pub fn replace_in_project(
+    project_path: String,
+    find: String,
+    replace: String,
+    mode: Option<SearchMode>,
+    dry_run: bool,
+) -> Result<Vec<ReplacementChange>, AppError> {
+    if find.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mode = mode.unwrap_or_default();
+    let matcher = compile_query(&find, mode)?;
+    let project = PathBuf::from(&project_path);
+    let manuscript = project.join("manuscript");
+    if !manuscript.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<PathBuf> = WalkDir::new(&manuscript)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .map(|e| e.path().to_path_buf())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    files.sort();
+
+    let mut changes: Vec<ReplacementChange> = Vec::new();
+
+    for file_path in files {
+        let content = std::fs::read_to_string(&file_path)?;
+        let (fm, _) = match parse_frontmatter(&content) {
+            Ok(r) => r,
+            Err(_) => continue,
+        };
+        let Some(offset) = body_offset(&content) else {
+            continue;
+        };
+
+        let (head, body) = content.split_at(offset);
+        let first_body_line = head.lines().count() + 1;
+        let mut new_body = String::with_capacity(body.len());
+        let mut file_changed = false;
+
+        for (i, segment) in body.split_inclusive('\n').enumerate() {
+            let line = segment.trim_end_matches(['\n', '\r']);
+            let ending = &segment[line.len()..];
+
+            if !matcher.is_match(line) {
+                new_body.push_str(segment);
+                continue;
+            }
+
+            let after = matcher.replace_all(line, &replace, mode == SearchMode::Regex);
+            if after != line {
+                file_changed = true;
+                changes.push(ReplacementChange {
+                    slug: fm.slug.clone(),
+                    line_number: first_body_line + i,
+                    before: line.to_string(),
+                    after: after.to_string(),
+                });
+            }
+            new_body.push_str(&after);
+            new_body.push_str(ending);
+        }
+
+        if file_changed && !dry_run {
+            std::fs::write(&file_path, format!("{}{}", head, new_body))?;
+            refresh_backlink_index(&project, &file_path);
+        }
+    }
+
+    Ok(changes)
+}
+
 /// Resolve a wiki-link text to its target file.
 ///
-/// Matches case-insensitively against file titles parsed from frontmatter.
-/// Returns the first match, or NotFound if no file has a matching title.
+/// Matches like `resolve_wiki_target`, by title or slug and including typed
+/// links, but returns the first match in path order instead of reporting ambiguity. Returns NotFound
+/// if no file matches.
 #[tauri::command]
 pub fn resolve_wiki_link(
     project_path: String,
     link_text: String,
 ) -> Result<WikiLinkTarget, AppError> {
+    let files = linkable_files(&PathBuf::from(&project_path));
+    let file = wiki_link_matches(&files, &link_text)
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            AppError::NotFound(format!("No file found with title matching: {}", link_text))
+        })?;
+
+    let (file_type, entity_type) = match &file.target {
+        WikiTarget::Chapter { .. } => ("chapter", None),
+        WikiTarget::Note { .. } => ("note", None),
+        WikiTarget::Entity { schema, .. } => ("entity", Some(schema.clone())),
+        WikiTarget::Ambiguous(_) => ("", None),
+    };
+    Ok(WikiLinkTarget {
+        title: file.title.clone(),
+        slug: file.slug().to_string(),
+        file_type: file_type.to_string(),
+        entity_type,
+    })
+}
+
+/// A chapter, note or entity that a wiki link can point at.
+pub(crate) struct LinkableFile {
+    pub(crate) title: String,
+    pub(crate) target: WikiTarget,
+}
+
+impl LinkableFile {
+    /// The prefix that selects this file's kind in a typed link: `chapter`,
+    /// `note`, or the entity's schema type.
+    fn kind(&self) -> &str {
+        match &self.target {
+            WikiTarget::Chapter { .. } => "chapter",
+            WikiTarget::Note { .. } => "note",
+            WikiTarget::Entity { schema, .. } => schema,
+            WikiTarget::Ambiguous(_) => "",
+        }
+    }
+
+    fn slug(&self) -> &str {
+        match &self.target {
+            WikiTarget::Chapter { slug }
+            | WikiTarget::Note { slug }
+            | WikiTarget::Entity { slug, .. } => slug,
+            WikiTarget::Ambiguous(_) => "",
+        }
+    }
+}
+
+/// Every chapter, note and entity in the project with a parseable title, in
+/// path order.
+pub(crate) fn linkable_files(project: &Path) -> Vec<LinkableFile> {
+    let mut paths = walk_md_files(project);
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|file_path| {
+            let content = std::fs::read_to_string(&file_path).ok()?;
+            let (fm, _) = parse_frontmatter(&content).ok()?;
+            linkable_file(project, &file_path, fm.title, fm.slug)
+        })
+        .collect()
+}
+
+/// The files recorded in a backlink index's `titles`, like `linkable_files`
+/// but without reading them.
+fn indexed_linkable_files(project: &Path, index: &BacklinkIndex) -> Vec<LinkableFile> {
+    index
+        .titles
+        .iter()
+        .filter_map(|(rel, indexed)| {
+            linkable_file(
+                project,
+                &project.join(rel),
+                indexed.title.clone(),
+                indexed.slug.clone(),
+            )
+        })
+        .collect()
+}
+
+/// A file's link target, from its path and frontmatter title and slug.
+fn linkable_file(
+    project: &Path,
+    file_path: &Path,
+    title: String,
+    slug: String,
+) -> Option<LinkableFile> {
+    let target = match classify_file(project, file_path)? {
+        (file_type, _) if file_type == "chapter" => WikiTarget::Chapter { slug },
+        (file_type, _) if file_type == "note" => WikiTarget::Note { slug },
+        (_, Some(schema)) => WikiTarget::Entity { schema, slug },
+        _ => return None,
+    };
+    Some(LinkableFile { title, target })
+}
+
+/// Whether a link's title part names a file: its title compared
+/// case-insensitively, or its slug compared exactly.
+pub(crate) fn link_names_file(title: &str, slug: &str, link_title: &str) -> bool {
+    link_title == slug || link_title.to_lowercase() == title.to_lowercase()
+}
+
+/// Files a wiki link's text names, by title or slug (see `link_names_file`).
+///
+/// A `kind:Title` link (e.g. `character:Alice`, `note:Ideas`) only matches
+/// files of that kind. A prefix that names no kind in `files` is treated as
+/// part of the title, so titles like "Part 1: Dawn" still resolve.
+pub(crate) fn wiki_link_matches<'a>(
+    files: &'a [LinkableFile],
+    link_text: &str,
+) -> Vec<&'a LinkableFile> {
+    let (kind, title) = split_link_kind(link_text, |k| files.iter().any(|f| f.kind() == k));
+
+    files
+        .iter()
+        .filter(|f| kind.as_deref().is_none_or(|k| f.kind() == k))
+        .filter(|f| link_names_file(&f.title, f.slug(), title))
+        .collect()
+}
+
+/// Resolve a wiki link against `files`: `None` if nothing matches,
+/// `Ambiguous` if several files do.
+pub(crate) fn resolve_wiki_target_in(
+    files: &[LinkableFile],
+    link_text: &str,
+) -> Option<WikiTarget> {
+    let mut matches = wiki_link_matches(files, link_text);
+    match matches.len() {
+        0 => None,
+        1 => matches.pop().map(|f| f.target.clone()),
+        _ => Some(WikiTarget::Ambiguous(
+            matches.into_iter().map(|f| f.target.clone()).collect(),
+        )),
+    }
+}
+
+/// Resolve a wiki link to the chapter, note or entity it names.
+///
+/// Supports typed links such as `[[character:Alice]]`. A bare title that
+/// matches files of several kinds returns `Ambiguous` listing every match.
+/// Returns NotFound when nothing matches.
+#[tauri::command]
+pub fn resolve_wiki_target(
+    project_path: String,
+    link_text: String,
+) -> Result<WikiTarget, AppError> {
+    let files = linkable_files(&PathBuf::from(&project_path));
+    resolve_wiki_target_in(&files, &link_text).ok_or_else(|| {
+        AppError::NotFound(format!("No file found with title matching: {}", link_text))
+    })
+}
+
+/// Maximum number of suggestions returned by `suggest_wiki_links`.
+const MAX_WIKI_SUGGESTIONS: usize = 20;
+
+/// Suggest wiki-link targets for a partially typed link.
+///
+/// Matches titles case-insensitively. Titles starting with `prefix` rank above
+/// titles merely containing it; ties are broken by file_type priority, then title.
+/// An empty prefix matches every file. Returns at most 20 candidates.
+#[tauri::command]
+pub fn suggest_wiki_links(
+    project_path: String,
+    prefix: String,
+) -> Result<Vec<WikiLinkCandidate>, AppError> {
     let project = PathBuf::from(&project_path);
-    let link_lower = link_text.to_lowercase();
+    let prefix_lower = prefix.to_lowercase();
+    let mut candidates: Vec<(bool, WikiLinkCandidate)> = Vec::new();
 
     for file_path in walk_md_files(&project) {
         let (file_type, entity_type) = match classify_file(&project, &file_path) {
@@ -282,35 +1088,92 @@ pub fn resolve_wiki_link(
             Err(_) => continue,
         };
 
-        if fm.title.to_lowercase() == link_lower {
-            return Ok(WikiLinkTarget {
+        let title_lower = fm.title.to_lowercase();
+        if !title_lower.contains(&prefix_lower) {
+            continue;
+        }
+
+        candidates.push((
+            title_lower.starts_with(&prefix_lower),
+            WikiLinkCandidate {
                 title: fm.title,
                 slug: fm.slug,
                 file_type,
                 entity_type,
-            });
-        }
+            },
+        ));
     }
 
-    Err(AppError::NotFound(format!(
-        "No file found with title matching: {}",
-        link_text
-    )))
+    candidates.sort_by(|a, b| {
+        b.0.cmp(&a.0)
+            .then_with(|| {
+                file_type_priority(&a.1.file_type).cmp(&file_type_priority(&b.1.file_type))
+            })
+            .then_with(|| a.1.title.to_lowercase().cmp(&b.1.title.to_lowercase()))
+    });
+
+    Ok(candidates
+        .into_iter()
+        .take(MAX_WIKI_SUGGESTIONS)
+        .map(|(_, c)| c)
+        .collect())
 }
 
 /// Find all files that contain a wiki-link to the given title.
 ///
-/// Searches for the pattern `[[{title}]]` (case-insensitive) in all .md files.
+/// Counts every link that resolves to a file with this title (see
+/// `wiki_link_matches`): by title, case-insensitively, or by slug, including
+/// typed links such as `[[character:{title}]]`. A typed link of another kind,
+/// like `[[note:Alice]]` for the character Alice, doesn't count. When no file
+/// has the title, links naming it of any kind count. When a backlink index exists only the files it lists are read,
+/// after rebuilding it if any file changed since it was written; otherwise
+/// every .md file is scanned.
 #[tauri::command]
 pub fn find_backlinks(
     project_path: String,
     title: String,
 ) -> Result<Vec<BacklinkResult>, AppError> {
     let project = PathBuf::from(&project_path);
-    let pattern = format!("[[{}]]", title.to_lowercase());
+    let kinds = link_kinds(&project);
+    let title_lower = title.to_lowercase();
     let mut results: Vec<BacklinkResult> = Vec::new();
 
-    for file_path in walk_md_files(&project) {
+    let index = load_fresh_backlink_index(&project);
+    let linkable = match &index {
+        Some(index) => indexed_linkable_files(&project, index),
+        None => linkable_files(&project),
+    };
+    let targets: Vec<&LinkableFile> = linkable
+        .iter()
+        .filter(|f| f.title.to_lowercase() == title_lower)
+        .collect();
+    let links_to_title = |link_text: &str| {
+        if targets.is_empty() {
+            return split_link_kind(link_text, |k| kinds.contains(k))
+                .1
+                .to_lowercase()
+                == title_lower;
+        }
+        wiki_link_matches(&linkable, link_text)
+            .into_iter()
+            .any(|f| targets.iter().any(|t| std::ptr::eq(*t, f)))
+    };
+
+    let files: Vec<PathBuf> = match &index {
+        Some(index) => index
+            .links
+            .iter()
+            // Keys are lowercased link texts, so they resolve like the links.
+            .filter(|(key, _)| links_to_title(key))
+            .flat_map(|(_, sources)| sources)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|rel| project.join(rel))
+            .collect(),
+        None => walk_md_files(&project),
+    };
+
+    for file_path in files {
         let (file_type, entity_type) = match classify_file(&project, &file_path) {
             Some(c) => c,
             None => continue,
@@ -327,7 +1190,8 @@ pub fn find_backlinks(
         };
 
         for (i, line) in content.lines().enumerate() {
-            if line.to_lowercase().contains(&pattern) {
+            let links_here = wiki_links_in_line(line).into_iter().any(&links_to_title);
+            if links_here {
                 results.push(BacklinkResult {
                     title: fm.title.clone(),
                     slug: fm.slug.clone(),
@@ -343,11 +1207,147 @@ pub fn find_backlinks(
     Ok(results)
 }
 
-// ── Tests ─────────────────────────────────────────────────────────
-
-#[cfg(test)]
+/// Build the backlink index from scratch and store it in `.sakya/backlinks.json`.
+#[tauri::command]
+pub fn build_backlink_index(project_path: String) -> Result<BacklinkIndex, AppError> {
+    let project = PathBuf::from(&project_path);
+    let kinds = link_kinds(&project);
+    let mut index = BacklinkIndex::default();
+    for file_path in walk_md_files(&project) {
+        reindex_file(&mut index, &project, &file_path, &kinds);
+    }
+    write_backlink_index(&project, &index)?;
+    Ok(index)
+}
+
+/// Refresh one file's outgoing links in the backlink index.
+///
+/// `file_path` is relative to the project root (e.g. `notes/ideas.md`). A
+/// deleted file is removed from the index. Builds the full index if none exists yet.
+#[tauri::command]
+pub fn update_backlink_index_for_file(
+    project_path: String,
+    file_path: String,
+) -> Result<BacklinkIndex, AppError> {
+    let project = PathBuf::from(&project_path);
+    let Some(mut index) = read_backlink_index(&project) else {
+        return build_backlink_index(project_path);
+    };
+
+    reindex_file(
+        &mut index,
+        &project,
+        &project.join(&file_path),
+        &link_kinds(&project),
+    );
+    write_backlink_index(&project, &index)?;
+    Ok(index)
+}
+
+/// Entities never referenced from any chapter or note.
+///
+/// An entity counts as referenced when a `[[wiki link]]` resolves to it (see
+/// `wiki_link_matches`), or when its title or slug appears as a whole word in
+/// a chapter or note body (case-insensitive). Other entities' files are not
+/// scanned, so entities that only reference each other are still reported.
+#[tauri::command]
+pub fn find_orphaned_entities(project_path: String) -> Result<Vec<EntitySummary>, AppError> {
+    let project = PathBuf::from(&project_path);
+    let files = linkable_files(&project);
+    let mut linked = BTreeSet::new();
+    let mut bodies = Vec::new();
+
+    for file_path in walk_md_files(&project) {
+        match classify_file(&project, &file_path) {
+            Some((file_type, _)) if file_type != "entity" => {}
+            _ => continue,
+        }
+        let Ok(content) = std::fs::read_to_string(&file_path) else {
+            continue;
+        };
+        let body = &content[body_offset(&content).unwrap_or(0)..];
+        for link_text in body.lines().flat_map(wiki_links_in_line) {
+            for file in wiki_link_matches(&files, link_text) {
+                if let WikiTarget::Entity { schema, slug } = &file.target {
+                    linked.insert((schema.clone(), slug.clone()));
+                }
+            }
+        }
+        bodies.push(body.to_string());
+    }
+
+    let mention = ContentSearchOptions {
+        case_insensitive: true,
+        whole_word: true,
+    };
+    let is_mentioned = |name: &str| {
+        !name.is_empty()
+            && bodies
+                .iter()
+                .any(|body| !find_content_matches(body, name, &mention).is_empty())
+    };
+
+    Ok(list_all_entities(&project_path)?
+        .into_iter()
+        .filter(|entity| {
+            !linked.contains(&(entity.schema_type.clone(), entity.slug.clone()))
+                && !is_mentioned(&entity.title)
+                && !is_mentioned(&entity.slug)
+        })
+        .collect())
+}
+
+/// Every `[[wiki link]]` in a chapter or note body that resolves to nothing.
+///
+/// Links are resolved like `resolve_wiki_target`, so typed links are honoured
+/// and ambiguous links count as resolved. Results follow file path order, then
+/// line order; line numbers count from the top of the file.
+#[tauri::command]
+pub fn find_broken_links(project_path: String) -> Result<Vec<BrokenLink>, AppError> {
+    let project = PathBuf::from(&project_path);
+    let files = linkable_files(&project);
+    let mut sources = walk_md_files(&project);
+    sources.sort();
+
+    let mut broken = Vec::new();
+    for file_path in sources {
+        let source_type = match classify_file(&project, &file_path) {
+            Some((file_type, _)) if file_type != "entity" => file_type,
+            _ => continue,
+        };
+        let Ok(content) = std::fs::read_to_string(&file_path) else {
+            continue;
+        };
+        let Ok((fm, first_body_line)) = parse_frontmatter(&content) else {
+            continue;
+        };
+        let Some(offset) = body_offset(&content) else {
+            continue;
+        };
+
+        for (i, line) in content[offset..].lines().enumerate() {
+            for link_text in wiki_links_in_line(line) {
+                if resolve_wiki_target_in(&files, link_text).is_none() {
+                    broken.push(BrokenLink {
+                        source_type: source_type.clone(),
+                        source_slug: fm.slug.clone(),
+                        link_text: link_text.to_string(),
+                        line_number: first_body_line + i + 1,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(broken)
+}
+
+// ── Tests ─────────────────────────────────────────────────────────
+
+#[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commands::notes::save_note;
     use crate::test_helpers::setup_test_dir;
 
     /// Helper: write a markdown file with frontmatter.
@@ -392,7 +1392,7 @@ mod tests {
             "Some content\n",
         );
 
-        let results = search_project(pp, String::new()).unwrap();
+        let results = search_project(pp, String::new(), None).unwrap();
         assert!(results.is_empty());
     }
 
@@ -409,7 +1409,7 @@ mod tests {
             "Hello world\n",
         );
 
-        let results = search_project(pp, "zzzznonexistent".to_string()).unwrap();
+        let results = search_project(pp, "zzzznonexistent".to_string(), None).unwrap();
         assert!(results.is_empty());
     }
 
@@ -426,7 +1426,7 @@ mod tests {
             "The dragon sleeps in the mountain.\nHeroes must find the sword.\n",
         );
 
-        let results = search_project(pp, "dragon".to_string()).unwrap();
+        let results = search_project(pp, "dragon".to_string(), None).unwrap();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].title, "Quest Ideas");
         assert_eq!(results[0].slug, "quest");
@@ -448,7 +1448,7 @@ mod tests {
             "The DRAGON roars loudly.\n",
         );
 
-        let results = search_project(pp, "dragon".to_string()).unwrap();
+        let results = search_project(pp, "dragon".to_string(), None).unwrap();
         assert_eq!(results.len(), 1);
         assert!(results[0].matching_line.contains("DRAGON"));
     }
@@ -480,7 +1480,7 @@ mod tests {
             "Crystals are important plot devices.\n",
         );
 
-        let results = search_project(pp, "crystal".to_string()).unwrap();
+        let results = search_project(pp, "crystal".to_string(), None).unwrap();
         assert!(results.len() >= 3);
 
         // Chapters should come before entities, entities before notes (by sort)
@@ -506,7 +1506,7 @@ mod tests {
             "Bob is a warrior.\n",
         );
 
-        let results = search_project(pp, "warrior".to_string()).unwrap();
+        let results = search_project(pp, "warrior".to_string(), None).unwrap();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].file_type, "entity");
         assert_eq!(results[0].entity_type, Some("character".to_string()));
@@ -527,7 +1527,7 @@ mod tests {
             "A powerful weapon.\n",
         );
 
-        let results = search_project(pp, "magic-sword".to_string()).unwrap();
+        let results = search_project(pp, "magic-sword".to_string(), None).unwrap();
         // Should not find anything — "magic-sword" is only in slug field, not title or body
         assert!(results.is_empty());
     }
@@ -545,7 +1545,7 @@ mod tests {
             "Some body text.\n",
         );
 
-        let results = search_project(pp, "Dragon Lore".to_string()).unwrap();
+        let results = search_project(pp, "Dragon Lore".to_string(), None).unwrap();
         assert!(!results.is_empty());
         assert_eq!(results[0].title, "Dragon Lore");
     }
@@ -570,7 +1570,7 @@ mod tests {
             "Magic is everywhere in this story.\n",
         );
 
-        let results = search_project(pp, "Magic".to_string()).unwrap();
+        let results = search_project(pp, "Magic".to_string(), None).unwrap();
         assert!(results.len() >= 2);
         // The exact title match ("Magic") should be first
         assert_eq!(results[0].title, "Magic");
@@ -589,7 +1589,7 @@ mod tests {
             "Line one.\nLine two with match.\nLine three.\n",
         );
 
-        let results = search_project(pp, "match".to_string()).unwrap();
+        let results = search_project(pp, "match".to_string(), None).unwrap();
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].context_before, "Line one.");
         assert_eq!(results[0].context_after, "Line three.");
@@ -607,7 +1607,7 @@ mod tests {
         }
         write_md(dir.path(), "notes/many.md", "Many Matches", "many", &body);
 
-        let results = search_project(pp, "searchterm".to_string()).unwrap();
+        let results = search_project(pp, "searchterm".to_string(), None).unwrap();
         assert_eq!(results.len(), 50);
     }
 
@@ -616,7 +1616,7 @@ mod tests {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        let results = search_project(pp, "anything".to_string()).unwrap();
+        let results = search_project(pp, "anything".to_string(), None).unwrap();
         assert!(results.is_empty());
     }
 
@@ -633,7 +1633,7 @@ mod tests {
             "First mention of sword.\nSecond mention of sword.\nThird mention of sword.\n",
         );
 
-        let results = search_project(pp, "sword".to_string()).unwrap();
+        let results = search_project(pp, "sword".to_string(), None).unwrap();
         assert_eq!(results.len(), 3);
         // All from same file
         assert!(results.iter().all(|r| r.slug == "multi"));
@@ -653,293 +1653,1293 @@ mod tests {
             "First body line.\nSecond body line with target.\n",
         );
 
-        let results = search_project(pp, "target".to_string()).unwrap();
+        let results = search_project(pp, "target".to_string(), None).unwrap();
         assert_eq!(results.len(), 1);
         // Line 1: ---, Line 2: title, Line 3: slug, Line 4: ---, Line 5: First body, Line 6: Second body
         assert_eq!(results[0].line_number, 6);
     }
 
-    // ── resolve_wiki_link ─────────────────────────────────────────
-
     #[test]
-    fn resolve_wiki_link_exact_match() {
+    fn search_regex_mode_matches_pattern() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
+        write_md(
+            dir.path(),
+            "manuscript/ch-1.md",
+            "Chapter One",
+            "ch-1",
+            "She paused\u{2014} then spoke.\nNo dash here.\nAnother\u{2014}pause.\n",
+        );
 
-        let target = resolve_wiki_link(pp, "The Hero".to_string()).unwrap();
-        assert_eq!(target.title, "The Hero");
-        assert_eq!(target.slug, "hero");
-        assert_eq!(target.file_type, "note");
-        assert!(target.entity_type.is_none());
+        let results = search_project(pp, "\u{2014} ".to_string(), Some(SearchMode::Regex)).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].matching_line.starts_with("She paused"));
     }
 
     #[test]
-    fn resolve_wiki_link_case_insensitive() {
+    fn search_regex_mode_supports_character_classes() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
+        write_md(
+            dir.path(),
+            "notes/years.md",
+            "Years",
+            "years",
+            "Founded in 1204.\nRuined long ago.\nRebuilt in 1390.\n",
+        );
 
-        let target = resolve_wiki_link(pp, "the hero".to_string()).unwrap();
-        assert_eq!(target.title, "The Hero");
-        assert_eq!(target.slug, "hero");
+        let results =
+            search_project(pp, r"\b1[0-9]{3}\b".to_string(), Some(SearchMode::Regex)).unwrap();
+        assert_eq!(results.len(), 2);
     }
 
     #[test]
-    fn resolve_wiki_link_not_found() {
+    fn search_invalid_regex_returns_error() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
-
-        let result = resolve_wiki_link(pp, "Nonexistent".to_string());
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
-        assert!(err_msg.contains("Not found") || err_msg.contains("not found"));
+        let result = search_project(pp, "(unclosed".to_string(), Some(SearchMode::Regex));
+        match result {
+            Err(AppError::Validation(msg)) => assert!(msg.contains("Invalid regex")),
+            other => panic!("expected validation error, got {:?}", other),
+        }
     }
 
     #[test]
-    fn resolve_wiki_link_finds_chapter() {
+    fn search_whole_word_does_not_match_inside_larger_word() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
         write_md(
             dir.path(),
-            "manuscript/ch1.md",
-            "The Beginning",
-            "ch1",
-            "Body.\n",
+            "notes/cats.md",
+            "Cats",
+            "cats",
+            "The category was wrong.\nA cat appeared.\n",
         );
 
-        let target = resolve_wiki_link(pp, "The Beginning".to_string()).unwrap();
-        assert_eq!(target.file_type, "chapter");
-        assert!(target.entity_type.is_none());
+        let results = search_project(pp, "cat".to_string(), Some(SearchMode::WholeWord)).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_line, "A cat appeared.");
     }
 
     #[test]
-    fn resolve_wiki_link_finds_entity() {
+    fn search_whole_word_respects_unicode_boundaries() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_entity_md(
+        write_md(
             dir.path(),
-            "location",
-            "castle",
-            "The Castle",
-            "A grand castle.\n",
+            "notes/names.md",
+            "Names",
+            "names",
+            "Zoë arrived.\nZoëlle stayed.\n",
         );
 
-        let target = resolve_wiki_link(pp, "The Castle".to_string()).unwrap();
-        assert_eq!(target.file_type, "entity");
-        assert_eq!(target.entity_type, Some("location".to_string()));
-        assert_eq!(target.slug, "castle");
+        let results = search_project(pp, "zoë".to_string(), Some(SearchMode::WholeWord)).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_line, "Zoë arrived.");
     }
 
     #[test]
-    fn resolve_wiki_link_empty_project() {
+    fn search_whole_word_allows_punctuation_at_query_edges() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        let result = resolve_wiki_link(pp, "Anything".to_string());
-        assert!(result.is_err());
-    }
+        write_md(
+            dir.path(),
+            "notes/asides.md",
+            "Asides",
+            "asides",
+            "Mr. Hale left.\nMrs. Hale stayed.\nShe paused (aside) briefly.\n",
+        );
 
-    #[test]
-    fn resolve_wiki_link_partial_match_does_not_resolve() {
-        let dir = setup_test_dir();
-        let pp = dir.path().to_str().unwrap().to_string();
+        let results =
+            search_project(pp.clone(), "Mr.".to_string(), Some(SearchMode::WholeWord)).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_line, "Mr. Hale left.");
 
-        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
+        let results =
+            search_project(pp, "(aside)".to_string(), Some(SearchMode::WholeWord)).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].matching_line, "She paused (aside) briefly.");
+    }
 
-        // Partial match should NOT resolve
-        let result = resolve_wiki_link(pp, "Hero".to_string());
-        assert!(result.is_err());
+    #[test]
+    fn search_mode_deserializes_snake_case() {
+        let mode: SearchMode = serde_json::from_str("\"whole_word\"").unwrap();
+        assert_eq!(mode, SearchMode::WholeWord);
     }
 
-    // ── find_backlinks ────────────────────────────────────────────
+    // ── replace_in_project ────────────────────────────────────────
 
     #[test]
-    fn find_backlinks_single_source() {
+    fn replace_dry_run_previews_without_writing() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
         write_md(
             dir.path(),
-            "manuscript/ch1.md",
+            "manuscript/ch-1.md",
             "Chapter One",
-            "ch1",
-            "The story mentions [[The Hero]] in passing.\n",
+            "ch-1",
+            "Mara drew her sword.\nThe night was quiet.\nMara ran.\n",
         );
+        let before = std::fs::read_to_string(dir.path().join("manuscript/ch-1.md")).unwrap();
 
-        let backlinks = find_backlinks(pp, "The Hero".to_string()).unwrap();
-        assert_eq!(backlinks.len(), 1);
-        assert_eq!(backlinks[0].title, "Chapter One");
-        assert_eq!(backlinks[0].slug, "ch1");
-        assert_eq!(backlinks[0].file_type, "chapter");
-        assert!(backlinks[0].matching_line.contains("[[The Hero]]"));
+        let changes =
+            replace_in_project(pp, "Mara".to_string(), "Elin".to_string(), None, true).unwrap();
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].slug, "ch-1");
+        assert_eq!(changes[0].line_number, 5);
+        assert_eq!(changes[0].before, "Mara drew her sword.");
+        assert_eq!(changes[0].after, "Elin drew her sword.");
+        assert_eq!(changes[1].line_number, 7);
+
+        let after = std::fs::read_to_string(dir.path().join("manuscript/ch-1.md")).unwrap();
+        assert_eq!(before, after);
     }
 
     #[test]
-    fn find_backlinks_multiple_sources() {
+    fn replace_applies_to_body_and_leaves_frontmatter_intact() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
         write_md(
             dir.path(),
-            "manuscript/ch1.md",
-            "Chapter One",
-            "ch1",
-            "See [[Target]] for details.\n",
+            "manuscript/mara.md",
+            "Mara Returns",
+            "mara",
+            "Mara came home.\n",
         );
-        write_md(
-            dir.path(),
-            "notes/idea.md",
-            "Idea",
-            "idea",
-            "Related to [[Target]] somehow.\n",
+
+        let changes = replace_in_project(
+            pp,
+            "Mara".to_string(),
+            "Elin".to_string(),
+            Some(SearchMode::WholeWord),
+            false,
+        )
+        .unwrap();
+        assert_eq!(changes.len(), 1);
+
+        let content = std::fs::read_to_string(dir.path().join("manuscript/mara.md")).unwrap();
+        assert_eq!(
+            content,
+            "---\ntitle: \"Mara Returns\"\nslug: \"mara\"\n---\nElin came home.\n"
         );
-        write_entity_md(
+    }
+
+    #[test]
+    fn replace_whole_word_skips_partial_matches() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
             dir.path(),
-            "character",
-            "bob",
-            "Bob",
-            "Bob links to [[Target]] as well.\n",
+            "manuscript/ch-1.md",
+            "Chapter One",
+            "ch-1",
+            "Ana met Anabel and Ana.\n",
         );
 
-        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
-        assert_eq!(backlinks.len(), 3);
+        let changes = replace_in_project(
+            pp,
+            "ana".to_string(),
+            "Eva".to_string(),
+            Some(SearchMode::WholeWord),
+            true,
+        )
+        .unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].after, "Eva met Anabel and Eva.");
     }
 
     #[test]
-    fn find_backlinks_case_insensitive() {
+    fn replace_only_touches_manuscript_files() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/n.md", "Note", "n", "Mara again.\n");
+
+        let changes =
+            replace_in_project(pp, "Mara".to_string(), "Elin".to_string(), None, false).unwrap();
+        assert!(changes.is_empty());
+
+        let content = std::fs::read_to_string(dir.path().join("notes/n.md")).unwrap();
+        assert!(content.contains("Mara again."));
+    }
+
+    #[test]
+    fn replace_regex_with_capture_groups() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
         write_md(
             dir.path(),
-            "notes/ref.md",
-            "Reference",
-            "ref",
-            "Links to [[target]] with lowercase.\n",
+            "manuscript/ch-1.md",
+            "Chapter One",
+            "ch-1",
+            "Smith, John waved.\nDoe, Jane nodded.\n",
         );
 
-        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
-        assert_eq!(backlinks.len(), 1);
-        assert_eq!(backlinks[0].slug, "ref");
+        let changes = replace_in_project(
+            pp,
+            r"(\w+), (\w+)".to_string(),
+            "$2 $1".to_string(),
+            Some(SearchMode::Regex),
+            false,
+        )
+        .unwrap();
+        assert_eq!(changes.len(), 2);
+
+        let content = std::fs::read_to_string(dir.path().join("manuscript/ch-1.md")).unwrap();
+        assert!(content.ends_with("---\nJohn Smith waved.\nJane Doe nodded.\n"));
     }
 
     #[test]
-    fn find_backlinks_no_backlinks() {
+    fn replace_literal_modes_do_not_expand_dollar_signs() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
         write_md(
             dir.path(),
-            "notes/alone.md",
-            "Alone",
-            "alone",
-            "No links here.\n",
+            "manuscript/ch-1.md",
+            "Chapter One",
+            "ch-1",
+            "It cost ten coins.\n",
         );
 
-        let backlinks = find_backlinks(pp, "Alone".to_string()).unwrap();
-        assert!(backlinks.is_empty());
+        replace_in_project(pp, "ten coins".to_string(), "$10".to_string(), None, false).unwrap();
+
+        let content = std::fs::read_to_string(dir.path().join("manuscript/ch-1.md")).unwrap();
+        assert!(content.contains("It cost $10."));
     }
 
     #[test]
-    fn find_backlinks_empty_project() {
+    fn replace_invalid_regex_returns_error() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        let backlinks = find_backlinks(pp, "Anything".to_string()).unwrap();
-        assert!(backlinks.is_empty());
+        let result = replace_in_project(
+            pp,
+            "[oops".to_string(),
+            String::new(),
+            Some(SearchMode::Regex),
+            true,
+        );
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    // ── search_content ────────────────────────────────────────────
+
+    fn insensitive() -> ContentSearchOptions {
+        ContentSearchOptions {
+            case_insensitive: true,
+            whole_word: false,
+        }
     }
 
     #[test]
-    fn find_backlinks_multiple_links_in_same_file() {
+    fn search_content_ranks_files_by_match_count() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
         write_md(
             dir.path(),
-            "manuscript/ch1.md",
+            "manuscript/ch-1.md",
             "Chapter One",
-            "ch1",
-            "First [[Target]] mention.\nSecond [[Target]] mention.\n",
+            "ch-1",
+            "The lantern flickered.\nShe raised the lantern high.\n",
+        );
+        write_md(
+            dir.path(),
+            "notes/props.md",
+            "Props",
+            "props",
+            "A brass lantern, dented.\n",
+        );
+        write_entity_md(dir.path(), "item", "lamp", "Lamp", "An old lantern.\n");
+        write_md(
+            dir.path(),
+            "notes/other.md",
+            "Other",
+            "other",
+            "Nothing relevant.\n",
         );
 
-        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
-        assert_eq!(backlinks.len(), 2);
-        assert!(backlinks.iter().all(|b| b.slug == "ch1"));
+        let results = search_content(pp, "lantern".to_string(), insensitive()).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].slug, "ch-1");
+        assert_eq!(results[0].file_type, "chapter");
+        assert_eq!(results[0].match_count, 2);
+        assert_eq!(results[1].slug, "props");
+        assert_eq!(results[1].match_count, 1);
     }
 
     #[test]
-    fn find_backlinks_line_number_is_correct() {
+    fn search_content_snippet_highlights_match() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let body = format!("{}the hidden key{}\n", "a".repeat(100), "b".repeat(100));
+        write_md(dir.path(), "notes/long.md", "Long", "long", &body);
+
+        let results = search_content(pp, "hidden".to_string(), insensitive()).unwrap();
+        let m = &results[0];
+        assert_eq!(&m.snippet[m.highlight_start..m.highlight_end], "hidden");
+        assert!(m.snippet.chars().count() <= 2 * SNIPPET_CONTEXT_CHARS + "hidden".len());
+        assert!(m.snippet.starts_with('a'));
+        assert!(m.snippet.ends_with('b'));
+    }
+
+    #[test]
+    fn search_content_case_insensitive_option() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
         write_md(
             dir.path(),
-            "notes/source.md",
-            "Source",
-            "source",
-            "Line one.\nLine two.\nLine three with [[Target]] link.\n",
+            "notes/case.md",
+            "Case",
+            "case",
+            "The DRAGON woke. A dragon slept.\n",
         );
 
-        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
-        assert_eq!(backlinks.len(), 1);
-        // Lines: 1=---, 2=title, 3=slug, 4=---, 5=Line one, 6=Line two, 7=Line three
-        assert_eq!(backlinks[0].line_number, 7);
+        let sensitive = search_content(
+            pp.clone(),
+            "dragon".to_string(),
+            ContentSearchOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(sensitive[0].match_count, 1);
+
+        let results = search_content(pp, "dragon".to_string(), insensitive()).unwrap();
+        assert_eq!(results[0].match_count, 2);
+        let m = &results[0];
+        assert_eq!(&m.snippet[m.highlight_start..m.highlight_end], "DRAGON");
     }
 
     #[test]
-    fn find_backlinks_does_not_match_partial_bracket_syntax() {
+    fn search_content_whole_word_option() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
         write_md(
             dir.path(),
-            "notes/source.md",
-            "Source",
-            "source",
-            "This has [Target] single brackets.\nThis has Target without brackets.\n",
+            "notes/words.md",
+            "Words",
+            "words",
+            "The cat sat on the category of cats.\n",
         );
 
-        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
-        assert!(backlinks.is_empty());
+        let options = ContentSearchOptions {
+            case_insensitive: false,
+            whole_word: true,
+        };
+        let results = search_content(pp, "cat".to_string(), options).unwrap();
+        assert_eq!(results[0].match_count, 1);
     }
 
     #[test]
-    fn find_backlinks_entity_includes_entity_type() {
+    fn search_content_skips_frontmatter() {
         let dir = setup_test_dir();
         let pp = dir.path().to_str().unwrap().to_string();
 
-        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
-        write_entity_md(
+        write_md(
             dir.path(),
-            "character",
-            "alice",
-            "Alice",
-            "Alice mentions [[Target]] here.\n",
+            "notes/fm.md",
+            "Secret Title",
+            "fm",
+            "Body text.\n",
         );
 
-        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
-        assert_eq!(backlinks.len(), 1);
-        assert_eq!(backlinks[0].file_type, "entity");
-        assert_eq!(backlinks[0].entity_type, Some("character".to_string()));
+        let results = search_content(pp, "secret".to_string(), insensitive()).unwrap();
+        assert!(results.is_empty());
     }
 
-    // ── classify_file ─────────────────────────────────────────────
-
     #[test]
-    fn classify_manuscript_file() {
+    fn search_content_no_matches() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
+            dir.path(),
+            "manuscript/ch-1.md",
+            "Chapter One",
+            "ch-1",
+            "Hello world\n",
+        );
+
+        let results = search_content(pp, "zzzznonexistent".to_string(), insensitive()).unwrap();
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn find_content_matches_maps_offsets_after_case_folding() {
+        // 'İ' lowercases to two chars, shifting byte offsets in the folded text.
+        let text = "İstanbul and ISTANBUL";
+        let matches = find_content_matches(text, "istanbul", &insensitive());
+        assert_eq!(matches.len(), 1);
+        let (start, end) = matches[0];
+        assert_eq!(&text[start..end], "ISTANBUL");
+    }
+
+    // ── resolve_wiki_link ─────────────────────────────────────────
+
+    #[test]
+    fn resolve_wiki_link_exact_match() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
+
+        let target = resolve_wiki_link(pp, "The Hero".to_string()).unwrap();
+        assert_eq!(target.title, "The Hero");
+        assert_eq!(target.slug, "hero");
+        assert_eq!(target.file_type, "note");
+        assert!(target.entity_type.is_none());
+    }
+
+    #[test]
+    fn resolve_wiki_link_case_insensitive() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
+
+        let target = resolve_wiki_link(pp, "the hero".to_string()).unwrap();
+        assert_eq!(target.title, "The Hero");
+        assert_eq!(target.slug, "hero");
+    }
+
+    #[test]
+    fn resolve_wiki_link_not_found() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
+
+        let result = resolve_wiki_link(pp, "Nonexistent".to_string());
+        assert!(result.is_err());
+        let err_msg = result.unwrap_err().to_string();
+        assert!(err_msg.contains("Not found") || err_msg.contains("not found"));
+    }
+
+    #[test]
+    fn resolve_wiki_link_finds_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "The Beginning",
+            "ch1",
+            "Body.\n",
+        );
+
+        let target = resolve_wiki_link(pp, "The Beginning".to_string()).unwrap();
+        assert_eq!(target.file_type, "chapter");
+        assert!(target.entity_type.is_none());
+    }
+
+    #[test]
+    fn resolve_wiki_link_finds_entity() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_entity_md(
+            dir.path(),
+            "location",
+            "castle",
+            "The Castle",
+            "A grand castle.\n",
+        );
+
+        let target = resolve_wiki_link(pp, "The Castle".to_string()).unwrap();
+        assert_eq!(target.file_type, "entity");
+        assert_eq!(target.entity_type, Some("location".to_string()));
+        assert_eq!(target.slug, "castle");
+    }
+
+    #[test]
+    fn resolve_wiki_link_accepts_slug() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_entity_md(
+            dir.path(),
+            "location",
+            "old-mill",
+            "The Old Mill",
+            "Body.\n",
+        );
+
+        let target = resolve_wiki_link(pp, "old-mill".to_string()).unwrap();
+        assert_eq!(target.title, "The Old Mill");
+        assert_eq!(target.entity_type, Some("location".to_string()));
+    }
+
+    #[test]
+    fn resolve_wiki_link_empty_project() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let result = resolve_wiki_link(pp, "Anything".to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolve_wiki_link_partial_match_does_not_resolve() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
+
+        // Partial match should NOT resolve
+        let result = resolve_wiki_link(pp, "Hero".to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resolve_wiki_link_honours_typed_link() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/alice.md", "Alice", "alice", "Body.\n");
+        write_entity_md(dir.path(), "character", "alice", "Alice", "Body.\n");
+
+        let target = resolve_wiki_link(pp.clone(), "note:Alice".to_string()).unwrap();
+        assert_eq!(target.file_type, "note");
+        let target = resolve_wiki_link(pp, "character:Alice".to_string()).unwrap();
+        assert_eq!(target.file_type, "entity");
+        assert_eq!(target.entity_type, Some("character".to_string()));
+    }
+
+    // ── resolve_wiki_target ───────────────────────────────────────
+
+    #[test]
+    fn resolve_wiki_target_unambiguous_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "The Beginning",
+            "ch1",
+            "Body.\n",
+        );
+        write_md(dir.path(), "notes/ideas.md", "Ideas", "ideas", "Body.\n");
+
+        let target = resolve_wiki_target(pp, "the beginning".to_string()).unwrap();
+        assert_eq!(
+            target,
+            WikiTarget::Chapter {
+                slug: "ch1".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn resolve_wiki_target_typed_entity_link() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/alice.md", "Alice", "alice", "Body.\n");
+        write_entity_md(dir.path(), "character", "alice", "Alice", "Body.\n");
+
+        let target = resolve_wiki_target(pp.clone(), "character:Alice".to_string()).unwrap();
+        assert_eq!(
+            target,
+            WikiTarget::Entity {
+                schema: "character".to_string(),
+                slug: "alice".to_string()
+            }
+        );
+        let target = resolve_wiki_target(pp, "note:alice".to_string()).unwrap();
+        assert_eq!(
+            target,
+            WikiTarget::Note {
+                slug: "alice".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn resolve_wiki_target_bare_title_across_kinds_is_ambiguous() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
+            dir.path(),
+            "manuscript/alice.md",
+            "Alice",
+            "alice",
+            "Body.\n",
+        );
+        write_entity_md(dir.path(), "character", "alice", "Alice", "Body.\n");
+
+        let target = resolve_wiki_target(pp, "Alice".to_string()).unwrap();
+        let WikiTarget::Ambiguous(targets) = target else {
+            panic!("expected Ambiguous, got {:?}", target);
+        };
+        assert_eq!(targets.len(), 2);
+        assert!(targets.contains(&WikiTarget::Chapter {
+            slug: "alice".to_string()
+        }));
+        assert!(targets.contains(&WikiTarget::Entity {
+            schema: "character".to_string(),
+            slug: "alice".to_string()
+        }));
+    }
+
+    #[test]
+    fn resolve_wiki_target_unknown_prefix_is_part_of_title() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
+            dir.path(),
+            "manuscript/dawn.md",
+            "Part 1: Dawn",
+            "dawn",
+            "Body.\n",
+        );
+
+        let target = resolve_wiki_target(pp.clone(), "Part 1: Dawn".to_string()).unwrap();
+        assert_eq!(
+            target,
+            WikiTarget::Chapter {
+                slug: "dawn".to_string()
+            }
+        );
+        let result = resolve_wiki_target(pp, "character:Dawn".to_string());
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn wiki_target_serializes_by_kind() {
+        let json = serde_json::to_string(&WikiTarget::Entity {
+            schema: "place".to_string(),
+            slug: "keep".to_string(),
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"entity":{"schema":"place","slug":"keep"}}"#);
+    }
+
+    // ── find_broken_links ─────────────────────────────────────────
+
+    #[test]
+    fn find_broken_links_reports_only_unresolved_links() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_entity_md(dir.path(), "character", "alice", "Alice", "Body.\n");
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Opening",
+            "ch1",
+            "Intro.\n[[Alice]] meets [[Bob]].\n",
+        );
+
+        let broken = find_broken_links(pp).unwrap();
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].source_type, "chapter");
+        assert_eq!(broken[0].source_slug, "ch1");
+        assert_eq!(broken[0].link_text, "Bob");
+        assert_eq!(broken[0].line_number, 6);
+    }
+
+    #[test]
+    fn slug_link_is_neither_broken_nor_orphaned() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_entity_md(dir.path(), "place", "old-mill", "The Old Mill", "Body.\n");
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Opening",
+            "ch1",
+            "Start at [[old-mill]] and [[Old-Mill]].\n",
+        );
+
+        let broken: Vec<String> = find_broken_links(pp.clone())
+            .unwrap()
+            .into_iter()
+            .map(|b| b.link_text)
+            .collect();
+        assert_eq!(broken, vec!["Old-Mill"]);
+        assert!(find_orphaned_entities(pp).unwrap().is_empty());
+    }
+
+    #[test]
+    fn find_broken_links_scans_notes_but_not_entities() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
+            dir.path(),
+            "notes/todo.md",
+            "Todo",
+            "todo",
+            "See [[character:Nobody]].\n",
+        );
+        write_entity_md(
+            dir.path(),
+            "character",
+            "alice",
+            "Alice",
+            "Knows [[Nowhere]].\n",
+        );
+
+        let broken = find_broken_links(pp).unwrap();
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].source_type, "note");
+        assert_eq!(broken[0].source_slug, "todo");
+        assert_eq!(broken[0].link_text, "character:Nobody");
+    }
+
+    #[test]
+    fn find_broken_links_ambiguous_link_is_not_broken() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/alice.md", "Alice", "alice", "Body.\n");
+        write_entity_md(dir.path(), "character", "alice", "Alice", "Body.\n");
+        write_md(dir.path(), "manuscript/ch1.md", "One", "ch1", "[[Alice]]\n");
+
+        assert!(find_broken_links(pp).unwrap().is_empty());
+    }
+
+    // ── suggest_wiki_links ────────────────────────────────────────
+
+    #[test]
+    fn suggest_wiki_links_matches_all_content_kinds() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
+            dir.path(),
+            "manuscript/paris.md",
+            "Paris at Dawn",
+            "paris",
+            "",
+        );
+        write_md(dir.path(), "notes/parley.md", "Parley Rules", "parley", "");
+        write_entity_md(dir.path(), "character", "paragon", "Paragon", "");
+        write_md(dir.path(), "notes/other.md", "Unrelated", "other", "");
+
+        let results = suggest_wiki_links(pp, "PAR".to_string()).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].file_type, "chapter");
+        assert_eq!(results[0].slug, "paris");
+        assert_eq!(results[1].file_type, "entity");
+        assert_eq!(results[1].entity_type.as_deref(), Some("character"));
+        assert_eq!(results[2].file_type, "note");
+        assert_eq!(results[2].title, "Parley Rules");
+    }
+
+    #[test]
+    fn suggest_wiki_links_ranks_prefix_before_substring() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "manuscript/sea.md", "The Sea", "sea", "");
+        write_md(
+            dir.path(),
+            "notes/seafarers.md",
+            "Seafarers",
+            "seafarers",
+            "",
+        );
+
+        let results = suggest_wiki_links(pp, "sea".to_string()).unwrap();
+        let slugs: Vec<&str> = results.iter().map(|r| r.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["seafarers", "sea"]);
+    }
+
+    #[test]
+    fn suggest_wiki_links_empty_prefix_is_bounded() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        for i in 0..30 {
+            write_md(
+                dir.path(),
+                &format!("notes/n{}.md", i),
+                &format!("Note {}", i),
+                &format!("n{}", i),
+                "",
+            );
+        }
+
+        let results = suggest_wiki_links(pp, String::new()).unwrap();
+        assert_eq!(results.len(), MAX_WIKI_SUGGESTIONS);
+    }
+
+    // ── find_backlinks ────────────────────────────────────────────
+
+    #[test]
+    fn find_backlinks_single_source() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/hero.md", "The Hero", "hero", "Body.\n");
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Chapter One",
+            "ch1",
+            "The story mentions [[The Hero]] in passing.\n",
+        );
+
+        let backlinks = find_backlinks(pp, "The Hero".to_string()).unwrap();
+        assert_eq!(backlinks.len(), 1);
+        assert_eq!(backlinks[0].title, "Chapter One");
+        assert_eq!(backlinks[0].slug, "ch1");
+        assert_eq!(backlinks[0].file_type, "chapter");
+        assert!(backlinks[0].matching_line.contains("[[The Hero]]"));
+    }
+
+    #[test]
+    fn find_backlinks_multiple_sources() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Chapter One",
+            "ch1",
+            "See [[Target]] for details.\n",
+        );
+        write_md(
+            dir.path(),
+            "notes/idea.md",
+            "Idea",
+            "idea",
+            "Related to [[Target]] somehow.\n",
+        );
+        write_entity_md(
+            dir.path(),
+            "character",
+            "bob",
+            "Bob",
+            "Bob links to [[Target]] as well.\n",
+        );
+
+        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
+        assert_eq!(backlinks.len(), 3);
+    }
+
+    #[test]
+    fn find_backlinks_case_insensitive() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
+        write_md(
+            dir.path(),
+            "notes/ref.md",
+            "Reference",
+            "ref",
+            "Links to [[target]] with lowercase.\n",
+        );
+
+        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
+        assert_eq!(backlinks.len(), 1);
+        assert_eq!(backlinks[0].slug, "ref");
+    }
+
+    #[test]
+    fn find_backlinks_no_backlinks() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
+            dir.path(),
+            "notes/alone.md",
+            "Alone",
+            "alone",
+            "No links here.\n",
+        );
+
+        let backlinks = find_backlinks(pp, "Alone".to_string()).unwrap();
+        assert!(backlinks.is_empty());
+    }
+
+    #[test]
+    fn find_backlinks_empty_project() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let backlinks = find_backlinks(pp, "Anything".to_string()).unwrap();
+        assert!(backlinks.is_empty());
+    }
+
+    #[test]
+    fn find_backlinks_multiple_links_in_same_file() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Chapter One",
+            "ch1",
+            "First [[Target]] mention.\nSecond [[Target]] mention.\n",
+        );
+
+        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
+        assert_eq!(backlinks.len(), 2);
+        assert!(backlinks.iter().all(|b| b.slug == "ch1"));
+    }
+
+    #[test]
+    fn find_backlinks_line_number_is_correct() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
+        write_md(
+            dir.path(),
+            "notes/source.md",
+            "Source",
+            "source",
+            "Line one.\nLine two.\nLine three with [[Target]] link.\n",
+        );
+
+        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
+        assert_eq!(backlinks.len(), 1);
+        // Lines: 1=---, 2=title, 3=slug, 4=---, 5=Line one, 6=Line two, 7=Line three
+        assert_eq!(backlinks[0].line_number, 7);
+    }
+
+    #[test]
+    fn find_backlinks_does_not_match_partial_bracket_syntax() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
+        write_md(
+            dir.path(),
+            "notes/source.md",
+            "Source",
+            "source",
+            "This has [Target] single brackets.\nThis has Target without brackets.\n",
+        );
+
+        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
+        assert!(backlinks.is_empty());
+    }
+
+    #[test]
+    fn find_backlinks_entity_includes_entity_type() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/target.md", "Target", "target", "Body.\n");
+        write_entity_md(
+            dir.path(),
+            "character",
+            "alice",
+            "Alice",
+            "Alice mentions [[Target]] here.\n",
+        );
+
+        let backlinks = find_backlinks(pp, "Target".to_string()).unwrap();
+        assert_eq!(backlinks.len(), 1);
+        assert_eq!(backlinks[0].file_type, "entity");
+        assert_eq!(backlinks[0].entity_type, Some("character".to_string()));
+    }
+
+    // ── Backlink index ────────────────────────────────────────────
+
+    #[test]
+    fn build_backlink_index_maps_targets_to_sources() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(
+            dir.path(),
+            "notes/a.md",
+            "A",
+            "a",
+            "See [[Dragon]] and [[Sword]].\n",
+        );
+        write_md(
+            dir.path(),
+            "manuscript/ch-1.md",
+            "Ch",
+            "ch-1",
+            "The [[dragon]] woke.\n",
+        );
+        write_md(dir.path(), "notes/b.md", "B", "b", "No links.\n");
+
+        let index = build_backlink_index(pp).unwrap();
+
+        let dragon: Vec<&str> = index.links["dragon"].iter().map(|s| s.as_str()).collect();
+        assert_eq!(dragon, vec!["manuscript/ch-1.md", "notes/a.md"]);
+        let sword: Vec<&str> = index.links["sword"].iter().map(|s| s.as_str()).collect();
+        assert_eq!(sword, vec!["notes/a.md"]);
+        assert_eq!(index.links.len(), 2);
+        assert_eq!(index.files.len(), 3);
+        assert!(dir.path().join(".sakya/backlinks.json").exists());
+    }
+
+    #[test]
+    fn update_backlink_index_for_file_refreshes_one_file() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/a.md", "A", "a", "See [[Dragon]].\n");
+        write_md(dir.path(), "notes/b.md", "B", "b", "Also [[Dragon]].\n");
+        build_backlink_index(pp.clone()).unwrap();
+
+        write_md(dir.path(), "notes/a.md", "A", "a", "Now [[Castle]] only.\n");
+        let index = update_backlink_index_for_file(pp.clone(), "notes/a.md".to_string()).unwrap();
+
+        let dragon: Vec<&str> = index.links["dragon"].iter().map(|s| s.as_str()).collect();
+        assert_eq!(dragon, vec!["notes/b.md"]);
+        assert!(index.links["castle"].contains("notes/a.md"));
+
+        let results = find_backlinks(pp, "Castle".to_string()).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].slug, "a");
+    }
+
+    #[test]
+    fn update_backlink_index_for_deleted_file_removes_it() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/a.md", "A", "a", "See [[Dragon]].\n");
+        build_backlink_index(pp.clone()).unwrap();
+
+        std::fs::remove_file(dir.path().join("notes/a.md")).unwrap();
+        let index = update_backlink_index_for_file(pp, "notes/a.md".to_string()).unwrap();
+
+        assert!(index.links.is_empty());
+        assert!(index.files.is_empty());
+    }
+
+    #[test]
+    fn find_backlinks_uses_fresh_index() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/a.md", "A", "a", "See [[Dragon]].\n");
+        write_md(dir.path(), "notes/b.md", "B", "b", "Also [[Dragon]].\n");
+        let mut index = build_backlink_index(pp.clone()).unwrap();
+
+        // Drop b.md from the cached target set; a fresh index is trusted as-is.
+        index.links.get_mut("dragon").unwrap().remove("notes/b.md");
+        write_backlink_index(dir.path(), &index).unwrap();
+
+        let results = find_backlinks(pp, "Dragon".to_string()).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].slug, "a");
+    }
+
+    #[test]
+    fn find_backlinks_rebuilds_index_when_files_added() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/a.md", "A", "a", "See [[Dragon]].\n");
+        build_backlink_index(pp.clone()).unwrap();
+
+        // A new file the index doesn't know about makes it stale.
+        write_md(dir.path(), "notes/b.md", "B", "b", "Also [[Dragon]].\n");
+
+        let results = find_backlinks(pp, "Dragon".to_string()).unwrap();
+        assert_eq!(results.len(), 2);
+
+        let index = read_backlink_index(dir.path()).unwrap();
+        assert!(index.links["dragon"].contains("notes/b.md"));
+    }
+
+    #[test]
+    fn find_backlinks_rebuilds_index_after_external_edit() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/a.md", "A", "a", "See [[Dragon]].\n");
+        build_backlink_index(pp.clone()).unwrap();
+
+        // Edited in place by another program: same files, new mtime.
+        write_md(dir.path(), "notes/a.md", "A", "a", "See [[Castle]].\n");
+        std::fs::File::options()
+            .write(true)
+            .open(dir.path().join("notes/a.md"))
+            .unwrap()
+            .set_modified(std::time::UNIX_EPOCH)
+            .unwrap();
+
+        assert!(find_backlinks(pp.clone(), "Dragon".to_string())
+            .unwrap()
+            .is_empty());
+        assert_eq!(find_backlinks(pp, "Castle".to_string()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn find_backlinks_rebuilds_index_when_nested_entity_added() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/a.md", "A", "a", "See [[Dragon]].\n");
+        build_backlink_index(pp.clone()).unwrap();
+
+        write_md(
+            dir.path(),
+            "entities/character/knights/bob.md",
+            "Bob",
+            "bob",
+            "Fears the [[Dragon]].\n",
+        );
+
+        let results = find_backlinks(pp, "Dragon".to_string()).unwrap();
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn saving_a_note_refreshes_the_index() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_md(dir.path(), "notes/a.md", "A", "a", "See [[Dragon]].\n");
+        build_backlink_index(pp.clone()).unwrap();
+
+        save_note(
+            pp.clone(),
+            "a".to_string(),
+            "A".to_string(),
+            "See [[Castle]].\n".to_string(),
+        )
+        .unwrap();
+
+        assert!(find_backlinks(pp.clone(), "Dragon".to_string())
+            .unwrap()
+            .is_empty());
+        assert_eq!(find_backlinks(pp, "Castle".to_string()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn extract_wiki_links_finds_every_link() {
+        let kinds = BTreeSet::new();
+        let links = extract_wiki_links("[[One]] and [[two]]\n[[Three]] [[unclosed", &kinds);
+        let links: Vec<&str> = links.iter().map(|s| s.as_str()).collect();
+        assert_eq!(links, vec!["one", "three", "two"]);
+    }
+
+    #[test]
+    fn wiki_link_spans_cover_brackets() {
+        let line = "a [[One]] b [[ two ]] [[open";
+        let spans = wiki_link_spans(line);
+        assert_eq!(spans.len(), 2);
+        assert_eq!(&line[spans[0].0.clone()], "[[One]]");
+        assert_eq!(spans[1].1, " two ");
+    }
+
+    #[test]
+    fn extract_wiki_links_keeps_known_kind_prefix() {
+        let kinds: BTreeSet<String> = ["character".to_string()].into();
+        let links = extract_wiki_links("[[Character: Alice]] [[Part 1: Dawn]]", &kinds);
+        let links: Vec<&str> = links.iter().map(|s| s.as_str()).collect();
+        assert_eq!(links, vec!["character:alice", "part 1: dawn"]);
+    }
+
+    #[test]
+    fn find_backlinks_includes_typed_links() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_entity_md(dir.path(), "character", "alice", "Alice", "Body.\n");
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Chapter One",
+            "ch1",
+            "Enter [[character:Alice]].\n",
+        );
+
+        let scanned = find_backlinks(pp.clone(), "Alice".to_string()).unwrap();
+        assert_eq!(scanned.len(), 1);
+        assert_eq!(scanned[0].slug, "ch1");
+
+        build_backlink_index(pp.clone()).unwrap();
+        let indexed = find_backlinks(pp, "Alice".to_string()).unwrap();
+        assert_eq!(indexed.len(), 1);
+        assert_eq!(indexed[0].slug, "ch1");
+    }
+
+    #[test]
+    fn find_backlinks_includes_slug_links() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_entity_md(
+            dir.path(),
+            "location",
+            "old-mill",
+            "The Old Mill",
+            "Body.\n",
+        );
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Chapter One",
+            "ch1",
+            "They met at [[old-mill]].\nLater, [[location:old-mill]] burned.\n",
+        );
+
+        let scanned = find_backlinks(pp.clone(), "The Old Mill".to_string()).unwrap();
+        assert_eq!(scanned.len(), 2);
+
+        build_backlink_index(pp.clone()).unwrap();
+        let indexed = find_backlinks(pp, "The Old Mill".to_string()).unwrap();
+        assert_eq!(indexed.len(), 2);
+    }
+
+    #[test]
+    fn find_backlinks_ignores_typed_links_of_another_kind() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        write_entity_md(dir.path(), "character", "alice", "Alice", "Body.\n");
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Chapter One",
+            "ch1",
+            "Enter [[character:Alice]].\nSee [[note:Alice]].\n",
+        );
+
+        let scanned = find_backlinks(pp.clone(), "Alice".to_string()).unwrap();
+        assert_eq!(scanned.len(), 1);
+        assert_eq!(scanned[0].line_number, 5);
+
+        let index = build_backlink_index(pp.clone()).unwrap();
+        assert!(index.links.contains_key("character:alice"));
+        assert!(index.links.contains_key("note:alice"));
+        let indexed = find_backlinks(pp, "Alice".to_string()).unwrap();
+        assert_eq!(indexed.len(), 1);
+        assert_eq!(indexed[0].line_number, 5);
+    }
+
+    // ── classify_file ─────────────────────────────────────────────
+
+    #[test]
+    fn classify_manuscript_file() {
         let project = Path::new("/project");
         let file = Path::new("/project/manuscript/ch1.md");
         let result = classify_file(project, file);
@@ -997,4 +2997,89 @@ mod tests {
         let result = parse_frontmatter(content);
         assert!(result.is_err());
     }
+
+    // ── find_orphaned_entities ────────────────────────────────────
+
+    fn orphan_titles(pp: &str) -> Vec<String> {
+        find_orphaned_entities(pp.to_string())
+            .unwrap()
+            .into_iter()
+            .map(|e| e.title)
+            .collect()
+    }
+
+    #[test]
+    fn orphaned_entities_reports_unreferenced_only() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_entity_md(dir.path(), "character", "elena", "Elena", "");
+        write_entity_md(dir.path(), "character", "marcus", "Marcus", "");
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Chapter 1",
+            "ch1",
+            "[[Elena]] opened the gate.\n",
+        );
+
+        assert_eq!(orphan_titles(&pp), vec!["Marcus"]);
+    }
+
+    #[test]
+    fn orphaned_entities_accepts_link_by_slug() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_entity_md(dir.path(), "place", "old-mill", "The Old Mill", "");
+        write_entity_md(dir.path(), "place", "harbor", "Harbor", "");
+        write_md(
+            dir.path(),
+            "notes/route.md",
+            "Route",
+            "route",
+            "Start at [[old-mill]] before dawn.\n",
+        );
+
+        assert_eq!(orphan_titles(&pp), vec!["Harbor"]);
+    }
+
+    #[test]
+    fn orphaned_entities_accepts_plain_title_mention() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_entity_md(dir.path(), "place", "old-mill", "The Old Mill", "");
+        write_entity_md(dir.path(), "character", "ann", "Ann", "");
+        write_md(
+            dir.path(),
+            "manuscript/ch1.md",
+            "Chapter 1",
+            "ch1",
+            "They sheltered in the old mill. Annika slept.\n",
+        );
+
+        // "Ann" only appears inside "Annika", which is not a mention.
+        assert_eq!(orphan_titles(&pp), vec!["Ann"]);
+    }
+
+    #[test]
+    fn orphaned_entities_ignores_references_from_entities_and_frontmatter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        write_entity_md(
+            dir.path(),
+            "character",
+            "elena",
+            "Elena",
+            "Sister of [[Marcus]].\n",
+        );
+        write_entity_md(dir.path(), "character", "marcus", "Marcus", "");
+        write_md(
+            dir.path(),
+            "notes/elena.md",
+            "Elena",
+            "elena-note",
+            "No links.\n",
+        );
+
+        assert_eq!(orphan_titles(&pp), vec!["Elena", "Marcus"]);
+    }
 }
@@ -1,10 +1,15 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 
-use chrono::{NaiveDate, Utc};
+use chrono::{Datelike, NaiveDate, Utc};
 
+use crate::commands::compile::count_words;
+use crate::commands::manuscript::get_chapter;
 use crate::error::AppError;
-use crate::models::session::{SessionStats, SessionsData, WritingSession};
+use crate::models::session::{
+    DailyWords, MonthlyReport, SessionStats, SessionsConfig, SessionsData, SprintProgress,
+    WritingSession,
+};
 use crate::services::yaml_service::{read_yaml, write_yaml};
 
 /// Path to the sessions data file within a project.
@@ -20,6 +25,7 @@ fn load_sessions(project_path: &str) -> Result<SessionsData, AppError> {
     let path = sessions_path(project_path);
     if !path.exists() {
         return Ok(SessionsData {
+            config: SessionsConfig::default(),
             sessions: Vec::new(),
         });
     }
@@ -32,9 +38,47 @@ fn save_sessions(project_path: &str, data: &SessionsData) -> Result<(), AppError
     write_yaml(&path, data)
 }
 
+/// Largest UTC offsets in use, in minutes (UTC-12:00 and UTC+14:00).
+const MIN_TIMEZONE_OFFSET: i32 = -12 * 60;
+const MAX_TIMEZONE_OFFSET: i32 = 14 * 60;
+
+/// Local calendar date of an RFC 3339 timestamp, `offset_minutes` from UTC.
+fn local_date(timestamp: &str, offset_minutes: i32) -> Option<NaiveDate> {
+    timestamp
+        .parse::<chrono::DateTime<Utc>>()
+        .ok()
+        .map(|dt| (dt + chrono::Duration::minutes(offset_minutes as i64)).date_naive())
+}
+
+/// Today's local calendar date, `offset_minutes` from UTC.
+fn local_today(offset_minutes: i32) -> NaiveDate {
+    (Utc::now() + chrono::Duration::minutes(offset_minutes as i64)).date_naive()
+}
+
+/// Aggregate words written per local calendar day, keyed by each session's
+/// start date. Sessions with unparseable start timestamps are skipped.
+fn daily_word_map(sessions: &[WritingSession], offset_minutes: i32) -> BTreeMap<NaiveDate, u32> {
+    let mut daily_words: BTreeMap<NaiveDate, u32> = BTreeMap::new();
+    for session in sessions {
+        if let Some(date) = local_date(&session.start, offset_minutes) {
+            *daily_words.entry(date).or_insert(0) += session.words_written;
+        }
+    }
+    daily_words
+}
+
+/// Pick the day with the most words from a daily word map.
+fn best_day(daily_words: &BTreeMap<NaiveDate, u32>) -> (Option<String>, u32) {
+    daily_words
+        .iter()
+        .max_by_key(|(_, &words)| words)
+        .map(|(date, &words)| (Some(date.format("%Y-%m-%d").to_string()), words))
+        .unwrap_or((None, 0))
+}
+
 /// Calculate aggregated statistics from a slice of sessions.
 /// This is a pure function with no side effects.
-fn calculate_stats(sessions: &[WritingSession]) -> SessionStats {
+fn calculate_stats(sessions: &[WritingSession], config: &SessionsConfig) -> SessionStats {
     if sessions.is_empty() {
         return SessionStats {
             total_sessions: 0,
@@ -47,6 +91,10 @@ fn calculate_stats(sessions: &[WritingSession]) -> SessionStats {
             monthly_average: 0.0,
             best_day_words: 0,
             best_day_date: None,
+            words_per_minute: 0.0,
+            words_today: 0,
+            goal_met_today: false,
+            streak_at_risk: false,
         };
     }
 
@@ -55,29 +103,17 @@ fn calculate_stats(sessions: &[WritingSession]) -> SessionStats {
     let total_minutes: f64 = sessions.iter().filter_map(|s| s.duration_minutes).sum();
 
     // Aggregate words per day (using the start date)
-    let mut daily_words: BTreeMap<NaiveDate, u32> = BTreeMap::new();
-    let mut session_dates: BTreeSet<NaiveDate> = BTreeSet::new();
-
-    for session in sessions {
-        if let Ok(dt) = session.start.parse::<chrono::DateTime<Utc>>() {
-            let date = dt.date_naive();
-            *daily_words.entry(date).or_insert(0) += session.words_written;
-            session_dates.insert(date);
-        }
-    }
+    let daily_words = daily_word_map(sessions, config.timezone_offset_minutes);
+    let session_dates: BTreeSet<NaiveDate> = daily_words.keys().copied().collect();
 
     // Best day
-    let (best_day_date, best_day_words) = daily_words
-        .iter()
-        .max_by_key(|(_, &words)| words)
-        .map(|(date, &words)| (Some(date.format("%Y-%m-%d").to_string()), words))
-        .unwrap_or((None, 0));
+    let (best_day_date, best_day_words) = best_day(&daily_words);
 
     // Streak calculation
-    let today = Utc::now().date_naive();
+    let today = local_today(config.timezone_offset_minutes);
     let sorted_dates: Vec<NaiveDate> = session_dates.into_iter().collect();
 
-    let current_streak = calculate_current_streak(&sorted_dates, today);
+    let current_streak = calculate_current_streak(&sorted_dates, today, config.streak_grace_days);
     let longest_streak = calculate_longest_streak(&sorted_dates);
 
     // Averages: based on the span from the first session date to today
@@ -92,6 +128,18 @@ fn calculate_stats(sessions: &[WritingSession]) -> SessionStats {
         (0.0, 0.0, 0.0)
     };
 
+    // Today's progress toward the daily goal
+    let words_today = daily_words.get(&today).copied().unwrap_or(0);
+    let goal_met_today = config.daily_goal.is_some_and(|goal| words_today >= goal);
+    let streak_at_risk = current_streak > 0 && !daily_words.contains_key(&today);
+
+    // Words per minute over active (non-paused) writing time
+    let words_per_minute = if total_minutes > 0.0 {
+        total_words as f64 / total_minutes
+    } else {
+        0.0
+    };
+
     SessionStats {
         total_sessions,
         total_words,
@@ -103,30 +151,36 @@ fn calculate_stats(sessions: &[WritingSession]) -> SessionStats {
         monthly_average,
         best_day_words,
         best_day_date,
+        words_per_minute,
+        words_today,
+        goal_met_today,
+        streak_at_risk,
     }
 }
 
 /// Calculate the current streak: consecutive calendar days counting backwards
-/// from today where at least one session was recorded.
-fn calculate_current_streak(sorted_dates: &[NaiveDate], today: NaiveDate) -> u32 {
+/// from the most recent session day, provided that day is no more than
+/// `grace_days` before today. With `grace_days = 1` a streak survives until
+/// the end of the day after the last session.
+fn calculate_current_streak(sorted_dates: &[NaiveDate], today: NaiveDate, grace_days: u32) -> u32 {
     if sorted_dates.is_empty() {
         return 0;
     }
 
     let date_set: BTreeSet<NaiveDate> = sorted_dates.iter().copied().collect();
 
-    // Start from today and count backwards
-    let mut streak = 0u32;
+    // Start from today and skip back over at most `grace_days` missed days
     let mut check_date = today;
-
-    // If today has no session, check yesterday (allow a 1-day grace for "current" streak)
-    if !date_set.contains(&check_date) {
-        check_date -= chrono::Duration::days(1);
-        if !date_set.contains(&check_date) {
+    let mut missed = 0u32;
+    while !date_set.contains(&check_date) {
+        if missed == grace_days {
             return 0;
         }
+        missed += 1;
+        check_date -= chrono::Duration::days(1);
     }
 
+    let mut streak = 0u32;
     while date_set.contains(&check_date) {
         streak += 1;
         check_date -= chrono::Duration::days(1);
@@ -162,8 +216,62 @@ fn calculate_longest_streak(sorted_dates: &[NaiveDate]) -> u32 {
     longest
 }
 
+/// Current word count of a chapter's body, or `None` if the chapter doesn't
+/// exist or its frontmatter can't be parsed.
+///
+/// Neither must stop a session starting or ending, so callers fall back to
+/// the caller-supplied word count instead. IO errors are returned.
+fn chapter_word_count(project_path: &str, chapter_slug: &str) -> Result<Option<u32>, AppError> {
+    match get_chapter(project_path.to_string(), chapter_slug.to_string()) {
+        Ok(chapter) => Ok(Some(count_words(&chapter.body) as u32)),
+        Err(AppError::NotFound(_) | AppError::Validation(_) | AppError::Yaml(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Add the change from the session's last seen word count to `word_count`
+/// to its gross `words_added` / `words_removed`. Sessions without a start
+/// snapshot have nothing to diff against and are left alone.
+fn accumulate_edit(session: &mut WritingSession, word_count: u32) {
+    let Some(last) = session.last_word_count.or(session.start_word_count) else {
+        return;
+    };
+    if word_count >= last {
+        session.words_added += word_count - last;
+    } else {
+        session.words_removed += last - word_count;
+    }
+    session.last_word_count = Some(word_count);
+}
+
+/// Record a save of `chapter_slug` in every open session on that chapter.
+///
+/// Called by `save_chapter` so gross edits are tracked per save rather than
+/// only as the net change between the start and end of a session.
+pub(crate) fn record_chapter_save(
+    project_path: &str,
+    chapter_slug: &str,
+    word_count: u32,
+) -> Result<(), AppError> {
+    let mut data = load_sessions(project_path)?;
+    let mut changed = false;
+    for session in data
+        .sessions
+        .iter_mut()
+        .filter(|s| s.end.is_none() && s.chapter_slug == chapter_slug)
+    {
+        accumulate_edit(session, word_count);
+        changed = true;
+    }
+    if changed {
+        save_sessions(project_path, &data)?;
+    }
+    Ok(())
+}
+
 /// Start a new writing session. Creates the sessions file if it doesn't exist.
-/// Returns the session ID (ISO 8601 timestamp).
+/// Snapshots the chapter's word count so `end_session` can compute the words
+/// written. Returns the session ID (ISO 8601 timestamp).
 #[tauri::command]
 pub fn start_session(
     project_path: &str,
@@ -172,6 +280,7 @@ pub fn start_session(
 ) -> Result<String, AppError> {
     let now = Utc::now();
     let id = now.to_rfc3339();
+    let start_word_count = chapter_word_count(project_path, chapter_slug)?;
 
     let session = WritingSession {
         id: id.clone(),
@@ -181,6 +290,13 @@ pub fn start_session(
         words_written: 0,
         chapter_slug: chapter_slug.to_string(),
         sprint_goal,
+        paused_seconds: 0.0,
+        paused_at: None,
+        start_word_count,
+        net_word_change: None,
+        words_added: 0,
+        words_removed: 0,
+        last_word_count: start_word_count,
     };
 
     let mut data = load_sessions(project_path)?;
@@ -190,8 +306,76 @@ pub fn start_session(
     Ok(id)
 }
 
-/// End an existing writing session by ID. Sets end time, calculates duration,
-/// and records word count.
+/// Fold an open pause (if any) into `paused_seconds`, ending it at `now`.
+fn close_pause(session: &mut WritingSession, now: chrono::DateTime<Utc>) {
+    if let Some(paused_at) = session.paused_at.take() {
+        if let Ok(pause_start) = paused_at.parse::<chrono::DateTime<Utc>>() {
+            session.paused_seconds += (now - pause_start).num_seconds().max(0) as f64;
+        }
+    }
+}
+
+/// Find an open (not yet ended) session by ID.
+fn find_open_session<'a>(
+    data: &'a mut SessionsData,
+    session_id: &str,
+) -> Result<&'a mut WritingSession, AppError> {
+    let session = data
+        .sessions
+        .iter_mut()
+        .find(|s| s.id == session_id)
+        .ok_or_else(|| AppError::NotFound(format!("Session not found: {}", session_id)))?;
+    if session.end.is_some() {
+        return Err(AppError::InvalidOperation(format!(
+            "Session already ended: {}",
+            session_id
+        )));
+    }
+    Ok(session)
+}
+
+/// Pause an open writing session. Paused time is excluded from its duration.
+#[tauri::command]
+pub fn pause_session(project_path: &str, session_id: &str) -> Result<(), AppError> {
+    let mut data = load_sessions(project_path)?;
+    let session = find_open_session(&mut data, session_id)?;
+
+    if session.paused_at.is_some() {
+        return Err(AppError::InvalidOperation(format!(
+            "Session already paused: {}",
+            session_id
+        )));
+    }
+    session.paused_at = Some(Utc::now().to_rfc3339());
+
+    save_sessions(project_path, &data)
+}
+
+/// Resume a paused writing session, accumulating the paused interval.
+#[tauri::command]
+pub fn resume_session(project_path: &str, session_id: &str) -> Result<(), AppError> {
+    let mut data = load_sessions(project_path)?;
+    let session = find_open_session(&mut data, session_id)?;
+
+    if session.paused_at.is_none() {
+        return Err(AppError::InvalidOperation(format!(
+            "Session is not paused: {}",
+            session_id
+        )));
+    }
+    close_pause(session, Utc::now());
+
+    save_sessions(project_path, &data)
+}
+
+/// End an existing writing session by ID. Sets end time, calculates duration
+/// (excluding paused time), and records word count.
+///
+/// When the session has a start snapshot and its chapter still exists, words
+/// written are the chapter's net growth since the start (0 after a net
+/// deletion), and any change since the last recorded save is added to the
+/// gross `words_added` / `words_removed`. The caller's `words_written` is
+/// only used otherwise.
 #[tauri::command]
 pub fn end_session(
     project_path: &str,
@@ -209,19 +393,121 @@ pub fn end_session(
     let now = Utc::now();
     let end_time = now.to_rfc3339();
 
-    // Calculate duration from start to now
+    // Close out a pause that is still open when the session ends
+    close_pause(session, now);
+
+    // Calculate active duration from start to now, excluding paused time
     if let Ok(start_dt) = session.start.parse::<chrono::DateTime<Utc>>() {
         let duration = now - start_dt;
-        session.duration_minutes = Some(duration.num_seconds() as f64 / 60.0);
+        let active_seconds = (duration.num_seconds() as f64 - session.paused_seconds).max(0.0);
+        session.duration_minutes = Some(active_seconds / 60.0);
     }
 
     session.end = Some(end_time);
     session.words_written = words_written;
 
+    if let Some(start_count) = session.start_word_count {
+        if let Some(end_count) = chapter_word_count(project_path, &session.chapter_slug)? {
+            accumulate_edit(session, end_count);
+            let change = end_count as i64 - start_count as i64;
+            session.net_word_change = Some(change);
+            session.words_written = change.max(0) as u32;
+        }
+    }
+
     save_sessions(project_path, &data)?;
     Ok(())
 }
 
+/// Report a session's progress towards its sprint goal given the words written
+/// so far. Reads the session but never modifies it.
+///
+/// Elapsed time runs from the stored start to the session's end (or now if it
+/// is still open), minus paused time including a pause still in progress.
+#[tauri::command]
+pub fn session_progress(
+    project_path: &str,
+    session_id: &str,
+    current_words: u32,
+) -> Result<SprintProgress, AppError> {
+    let data = load_sessions(project_path)?;
+    let mut session = data
+        .sessions
+        .into_iter()
+        .find(|s| s.id == session_id)
+        .ok_or_else(|| AppError::NotFound(format!("Session not found: {}", session_id)))?;
+
+    let now = session
+        .end
+        .as_deref()
+        .and_then(|end| end.parse::<chrono::DateTime<Utc>>().ok())
+        .unwrap_or_else(Utc::now);
+    close_pause(&mut session, now);
+    let elapsed_minutes = session
+        .start
+        .parse::<chrono::DateTime<Utc>>()
+        .map(|start| ((now - start).num_seconds() as f64 - session.paused_seconds).max(0.0) / 60.0)
+        .unwrap_or(0.0);
+
+    let goal = session.sprint_goal;
+    Ok(SprintProgress {
+        words: current_words,
+        goal,
+        percent: goal
+            .filter(|g| *g > 0)
+            .map(|g| current_words as f64 / g as f64 * 100.0),
+        goal_met: goal.is_some_and(|g| current_words >= g),
+        elapsed_minutes,
+    })
+}
+
+/// Remove a session by ID, e.g. one started by mistake.
+#[tauri::command]
+pub fn delete_session(project_path: &str, session_id: &str) -> Result<(), AppError> {
+    let mut data = load_sessions(project_path)?;
+    let index = data
+        .sessions
+        .iter()
+        .position(|s| s.id == session_id)
+        .ok_or_else(|| AppError::NotFound(format!("Session not found: {}", session_id)))?;
+    data.sessions.remove(index);
+    save_sessions(project_path, &data)
+}
+
+/// Correct the word count and/or chapter of an ended session. Fields left as
+/// `None` are kept; timestamps and durations are never touched.
+#[tauri::command]
+pub fn amend_session(
+    project_path: &str,
+    session_id: &str,
+    words_written: Option<u32>,
+    chapter_slug: Option<String>,
+) -> Result<WritingSession, AppError> {
+    let mut data = load_sessions(project_path)?;
+    let session = data
+        .sessions
+        .iter_mut()
+        .find(|s| s.id == session_id)
+        .ok_or_else(|| AppError::NotFound(format!("Session not found: {}", session_id)))?;
+    if session.end.is_none() {
+        return Err(AppError::InvalidOperation(format!(
+            "Cannot amend a session that is still open: {}",
+            session_id
+        )));
+    }
+
+    if let Some(words) = words_written {
+        session.words_written = words;
+    }
+    if let Some(slug) = chapter_slug {
+        session.chapter_slug = slug;
+    }
+    let amended = session.clone();
+
+    save_sessions(project_path, &data)?;
+    Ok(amended)
+}
+
 /// Get writing sessions, optionally filtered by date range.
 /// `from` and `to` are ISO 8601 date strings (e.g. "2026-02-01").
 #[tauri::command]
@@ -231,6 +517,7 @@ pub fn get_sessions(
     to: Option<&str>,
 ) -> Result<Vec<WritingSession>, AppError> {
     let data = load_sessions(project_path)?;
+    let offset = data.config.timezone_offset_minutes;
 
     let from_date = from.and_then(|f| NaiveDate::parse_from_str(f, "%Y-%m-%d").ok());
     let to_date = to.and_then(|t| NaiveDate::parse_from_str(t, "%Y-%m-%d").ok());
@@ -239,11 +526,7 @@ pub fn get_sessions(
         .sessions
         .into_iter()
         .filter(|session| {
-            let session_date = session
-                .start
-                .parse::<chrono::DateTime<Utc>>()
-                .ok()
-                .map(|dt| dt.date_naive());
+            let session_date = local_date(&session.start, offset);
 
             match session_date {
                 Some(date) => {
@@ -259,11 +542,131 @@ pub fn get_sessions(
     Ok(filtered)
 }
 
+/// Quote a CSV field if it contains a comma, quote, or line break, doubling
+/// any embedded quotes.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Export writing sessions as CSV, filtered by the same date range as `get_sessions`.
+/// The first line is a header row; optional fields are left empty when unset.
+#[tauri::command]
+pub fn export_sessions_csv(
+    project_path: &str,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<String, AppError> {
+    let sessions = get_sessions(project_path, from, to)?;
+
+    let mut csv =
+        String::from("id,start,end,duration_minutes,words_written,chapter_slug,sprint_goal\n");
+    for session in &sessions {
+        let row = [
+            csv_field(&session.id),
+            csv_field(&session.start),
+            csv_field(session.end.as_deref().unwrap_or("")),
+            session
+                .duration_minutes
+                .map(|d| format!("{:.2}", d))
+                .unwrap_or_default(),
+            session.words_written.to_string(),
+            csv_field(&session.chapter_slug),
+            session
+                .sprint_goal
+                .map(|g| g.to_string())
+                .unwrap_or_default(),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    Ok(csv)
+}
+
+/// Build a per-day report for one calendar month. Days without sessions are
+/// included with zero words.
+#[tauri::command]
+pub fn get_monthly_report(
+    project_path: &str,
+    year: i32,
+    month: u32,
+) -> Result<MonthlyReport, AppError> {
+    let first_day = NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or_else(|| AppError::Validation(format!("Invalid month: {}-{}", year, month)))?;
+
+    let data = load_sessions(project_path)?;
+    let offset = data.config.timezone_offset_minutes;
+    let in_month: Vec<WritingSession> = data
+        .sessions
+        .into_iter()
+        .filter(|s| {
+            local_date(&s.start, offset).is_some_and(|d| d.year() == year && d.month() == month)
+        })
+        .collect();
+
+    let daily_words = daily_word_map(&in_month, offset);
+    let (best_day_date, best_day_words) = best_day(&daily_words);
+
+    let days: Vec<DailyWords> = first_day
+        .iter_days()
+        .take_while(|d| d.month() == month)
+        .map(|date| DailyWords {
+            date: date.format("%Y-%m-%d").to_string(),
+            words: daily_words.get(&date).copied().unwrap_or(0),
+        })
+        .collect();
+
+    Ok(MonthlyReport {
+        year,
+        month,
+        days,
+        total_words: in_month.iter().map(|s| s.words_written as u64).sum(),
+        total_minutes: in_month.iter().filter_map(|s| s.duration_minutes).sum(),
+        writing_days: daily_words.len() as u32,
+        best_day_words,
+        best_day_date,
+    })
+}
+
+/// Set (or clear) the daily word goal stored in the sessions file.
+#[tauri::command]
+pub fn set_daily_goal(project_path: &str, daily_goal: Option<u32>) -> Result<(), AppError> {
+    let mut data = load_sessions(project_path)?;
+    data.config.daily_goal = daily_goal;
+    save_sessions(project_path, &data)
+}
+
+/// Set how many missed days, counting back from today, keep a streak current.
+#[tauri::command]
+pub fn set_streak_grace_days(project_path: &str, grace_days: u32) -> Result<(), AppError> {
+    let mut data = load_sessions(project_path)?;
+    data.config.streak_grace_days = grace_days;
+    save_sessions(project_path, &data)
+}
+
+/// Set the writer's UTC offset in minutes, used to bucket sessions by local day.
+#[tauri::command]
+pub fn set_timezone_offset(project_path: &str, offset_minutes: i32) -> Result<(), AppError> {
+    if !(MIN_TIMEZONE_OFFSET..=MAX_TIMEZONE_OFFSET).contains(&offset_minutes) {
+        return Err(AppError::Validation(format!(
+            "Timezone offset must be between {} and {} minutes, got {}",
+            MIN_TIMEZONE_OFFSET, MAX_TIMEZONE_OFFSET, offset_minutes
+        )));
+    }
+    let mut data = load_sessions(project_path)?;
+    data.config.timezone_offset_minutes = offset_minutes;
+    save_sessions(project_path, &data)
+}
+
 /// Get aggregated session statistics for the project.
 #[tauri::command]
 pub fn get_session_stats(project_path: &str) -> Result<SessionStats, AppError> {
     let data = load_sessions(project_path)?;
-    Ok(calculate_stats(&data.sessions))
+    Ok(calculate_stats(&data.sessions, &data.config))
 }
 
 #[cfg(test)]
@@ -284,10 +687,34 @@ mod tests {
 
     /// Write a sessions.yaml directly for testing.
     fn write_test_sessions(project_path: &str, sessions: Vec<WritingSession>) {
-        let data = SessionsData { sessions };
+        let data = SessionsData {
+            config: SessionsConfig::default(),
+            sessions,
+        };
         save_sessions(project_path, &data).unwrap();
     }
 
+    /// A blank, never-ended session on `chapter-1`; tests override the
+    /// fields they care about with struct-update syntax.
+    fn base_session() -> WritingSession {
+        WritingSession {
+            id: String::new(),
+            start: String::new(),
+            end: None,
+            duration_minutes: None,
+            words_written: 0,
+            chapter_slug: "chapter-1".to_string(),
+            sprint_goal: None,
+            paused_seconds: 0.0,
+            paused_at: None,
+            start_word_count: None,
+            net_word_change: None,
+            words_added: 0,
+            words_removed: 0,
+            last_word_count: None,
+        }
+    }
+
     // ── start_session ───────────────────────────────────────────────
 
     #[test]
@@ -388,11 +815,7 @@ mod tests {
         let session = WritingSession {
             id: start_time.to_rfc3339(),
             start: start_time.to_rfc3339(),
-            end: None,
-            duration_minutes: None,
-            words_written: 0,
-            chapter_slug: "chapter-1".to_string(),
-            sprint_goal: None,
+            ..base_session()
         };
         write_test_sessions(&path, vec![session.clone()]);
 
@@ -446,121 +869,422 @@ mod tests {
         assert!(data.sessions[1].end.is_none());
     }
 
-    // ── get_sessions ────────────────────────────────────────────────
+    // ── chapter word snapshots ──────────────────────────────────────
+
+    /// Create a chapter titled "Chapter 1" (slug `chapter-1`) with `body`.
+    fn write_chapter_body(project_path: &str, body: &str) {
+        use crate::commands::manuscript::{create_chapter, save_chapter};
+
+        let chapter = match get_chapter(project_path.to_string(), "chapter-1".to_string()) {
+            Ok(chapter) => chapter,
+            Err(_) => create_chapter(project_path.to_string(), "Chapter 1".to_string()).unwrap(),
+        };
+        save_chapter(
+            project_path.to_string(),
+            chapter.slug,
+            chapter.frontmatter,
+            body.to_string(),
+        )
+        .unwrap();
+    }
 
     #[test]
-    fn get_sessions_returns_all_when_no_filter() {
+    fn start_session_snapshots_chapter_word_count() {
         let (_dir, path) = setup_session_test();
+        write_chapter_body(&path, "one two three");
 
         start_session(&path, "chapter-1", None).unwrap();
-        start_session(&path, "chapter-2", None).unwrap();
+        start_session(&path, "missing", None).unwrap();
 
-        let sessions = get_sessions(&path, None, None).unwrap();
-        assert_eq!(sessions.len(), 2);
+        let data = load_sessions(&path).unwrap();
+        assert_eq!(data.sessions[0].start_word_count, Some(3));
+        assert_eq!(data.sessions[1].start_word_count, None);
     }
 
     #[test]
-    fn get_sessions_returns_empty_for_new_project() {
+    fn start_session_with_unparseable_chapter_has_no_snapshot() {
         let (_dir, path) = setup_session_test();
-
-        let sessions = get_sessions(&path, None, None).unwrap();
-        assert!(sessions.is_empty());
+        let manuscript = std::path::Path::new(&path).join("manuscript");
+        std::fs::create_dir_all(&manuscript).unwrap();
+        std::fs::write(
+            manuscript.join("broken.md"),
+            "---\ntitle: [unclosed\n---\nBody.\n",
+        )
+        .unwrap();
+
+        let id = start_session(&path, "broken", None).unwrap();
+        end_session(&path, &id, 120).unwrap();
+
+        let session = &load_sessions(&path).unwrap().sessions[0];
+        assert_eq!(session.start_word_count, None);
+        assert_eq!(session.words_written, 120);
     }
 
     #[test]
-    fn get_sessions_filters_by_date_range() {
+    fn end_session_computes_words_added_from_snapshot() {
         let (_dir, path) = setup_session_test();
+        write_chapter_body(&path, "one two three");
 
-        let sessions = vec![
-            WritingSession {
-                id: "2026-02-10T10:00:00Z".to_string(),
-                start: "2026-02-10T10:00:00Z".to_string(),
-                end: Some("2026-02-10T10:30:00Z".to_string()),
-                duration_minutes: Some(30.0),
-                words_written: 300,
-                chapter_slug: "chapter-1".to_string(),
-                sprint_goal: None,
-            },
-            WritingSession {
-                id: "2026-02-12T10:00:00Z".to_string(),
-                start: "2026-02-12T10:00:00Z".to_string(),
-                end: Some("2026-02-12T10:30:00Z".to_string()),
-                duration_minutes: Some(30.0),
-                words_written: 500,
-                chapter_slug: "chapter-1".to_string(),
-                sprint_goal: None,
-            },
-            WritingSession {
-                id: "2026-02-14T10:00:00Z".to_string(),
-                start: "2026-02-14T10:00:00Z".to_string(),
-                end: Some("2026-02-14T10:30:00Z".to_string()),
-                duration_minutes: Some(30.0),
-                words_written: 700,
-                chapter_slug: "chapter-2".to_string(),
-                sprint_goal: None,
-            },
-        ];
-        write_test_sessions(&path, sessions);
+        let id = start_session(&path, "chapter-1", None).unwrap();
+        write_chapter_body(&path, "one two three four five six seven");
+        end_session(&path, &id, 999).unwrap();
 
-        // Filter: only Feb 11-13 (should get the Feb 12 session)
-        let filtered = get_sessions(&path, Some("2026-02-11"), Some("2026-02-13")).unwrap();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].words_written, 500);
+        let session = &load_sessions(&path).unwrap().sessions[0];
+        assert_eq!(session.words_written, 4);
+        assert_eq!(session.net_word_change, Some(4));
     }
 
     #[test]
-    fn get_sessions_filters_with_only_from() {
+    fn end_session_net_deletion_records_zero_words_written() {
         let (_dir, path) = setup_session_test();
+        write_chapter_body(&path, "one two three four five");
 
-        let sessions = vec![
-            WritingSession {
-                id: "2026-02-10T10:00:00Z".to_string(),
-                start: "2026-02-10T10:00:00Z".to_string(),
-                end: None,
-                duration_minutes: None,
-                words_written: 300,
-                chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
-            },
-            WritingSession {
-                id: "2026-02-14T10:00:00Z".to_string(),
-                start: "2026-02-14T10:00:00Z".to_string(),
-                end: None,
-                duration_minutes: None,
-                words_written: 700,
-                chapter_slug: "ch-2".to_string(),
-                sprint_goal: None,
-            },
-        ];
-        write_test_sessions(&path, sessions);
+        let id = start_session(&path, "chapter-1", None).unwrap();
+        write_chapter_body(&path, "one two");
+        end_session(&path, &id, 999).unwrap();
 
-        let filtered = get_sessions(&path, Some("2026-02-12"), None).unwrap();
-        assert_eq!(filtered.len(), 1);
-        assert_eq!(filtered[0].words_written, 700);
+        let session = &load_sessions(&path).unwrap().sessions[0];
+        assert_eq!(session.words_written, 0);
+        assert_eq!(session.net_word_change, Some(-3));
+        assert_eq!(session.words_removed, 3);
     }
 
     #[test]
-    fn get_sessions_filters_with_only_to() {
+    fn end_session_tracks_gross_edits_across_saves() {
         let (_dir, path) = setup_session_test();
+        write_chapter_body(&path, "one two three");
 
-        let sessions = vec![
-            WritingSession {
-                id: "2026-02-10T10:00:00Z".to_string(),
-                start: "2026-02-10T10:00:00Z".to_string(),
-                end: None,
-                duration_minutes: None,
-                words_written: 300,
-                chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
-            },
-            WritingSession {
-                id: "2026-02-14T10:00:00Z".to_string(),
-                start: "2026-02-14T10:00:00Z".to_string(),
-                end: None,
-                duration_minutes: None,
+        let id = start_session(&path, "chapter-1", None).unwrap();
+        write_chapter_body(&path, "one two three four five six");
+        write_chapter_body(&path, "one two");
+        write_chapter_body(&path, "one two three four");
+        end_session(&path, &id, 999).unwrap();
+
+        let session = &load_sessions(&path).unwrap().sessions[0];
+        assert_eq!(session.net_word_change, Some(1));
+        assert_eq!(session.words_written, 1);
+        assert_eq!(session.words_added, 5);
+        assert_eq!(session.words_removed, 4);
+    }
+
+    #[test]
+    fn chapter_saves_leave_ended_sessions_alone() {
+        let (_dir, path) = setup_session_test();
+        write_chapter_body(&path, "one two three");
+
+        let id = start_session(&path, "chapter-1", None).unwrap();
+        end_session(&path, &id, 0).unwrap();
+        write_chapter_body(&path, "one two three four five");
+
+        let session = &load_sessions(&path).unwrap().sessions[0];
+        assert_eq!(session.words_added, 0);
+        assert_eq!(session.last_word_count, Some(3));
+    }
+
+    #[test]
+    fn end_session_without_snapshot_uses_supplied_words() {
+        let (_dir, path) = setup_session_test();
+
+        let id = start_session(&path, "chapter-1", None).unwrap();
+        write_chapter_body(&path, "written after the session started");
+        end_session(&path, &id, 250).unwrap();
+
+        let session = &load_sessions(&path).unwrap().sessions[0];
+        assert_eq!(session.words_written, 250);
+        assert_eq!(session.net_word_change, None);
+    }
+
+    // ── session_progress ────────────────────────────────────────────
+
+    /// Write one open session with a 500-word goal, started `minutes` ago.
+    fn write_sprint_session(project_path: &str, minutes: i64) -> String {
+        let start = (Utc::now() - chrono::Duration::minutes(minutes)).to_rfc3339();
+        write_test_sessions(
+            project_path,
+            vec![WritingSession {
+                id: start.clone(),
+                start: start.clone(),
+                sprint_goal: Some(500),
+                ..base_session()
+            }],
+        );
+        start
+    }
+
+    #[test]
+    fn session_progress_below_goal() {
+        let (_dir, path) = setup_session_test();
+        let id = write_sprint_session(&path, 10);
+
+        let progress = session_progress(&path, &id, 200).unwrap();
+        assert_eq!(progress.words, 200);
+        assert_eq!(progress.goal, Some(500));
+        assert_eq!(progress.percent, Some(40.0));
+        assert!(!progress.goal_met);
+    }
+
+    #[test]
+    fn session_progress_exactly_at_goal() {
+        let (_dir, path) = setup_session_test();
+        let id = write_sprint_session(&path, 10);
+
+        let progress = session_progress(&path, &id, 500).unwrap();
+        assert_eq!(progress.percent, Some(100.0));
+        assert!(progress.goal_met);
+    }
+
+    #[test]
+    fn session_progress_over_goal_is_not_clamped() {
+        let (_dir, path) = setup_session_test();
+        let id = write_sprint_session(&path, 10);
+
+        let progress = session_progress(&path, &id, 750).unwrap();
+        assert_eq!(progress.percent, Some(150.0));
+        assert!(progress.goal_met);
+    }
+
+    #[test]
+    fn session_progress_elapsed_from_stored_start() {
+        let (_dir, path) = setup_session_test();
+        let id = write_sprint_session(&path, 30);
+
+        let progress = session_progress(&path, &id, 0).unwrap();
+        assert!(
+            (29.9..30.5).contains(&progress.elapsed_minutes),
+            "elapsed {}",
+            progress.elapsed_minutes
+        );
+    }
+
+    #[test]
+    fn session_progress_without_goal_is_never_met() {
+        let (_dir, path) = setup_session_test();
+        let id = start_session(&path, "chapter-1", None).unwrap();
+
+        let progress = session_progress(&path, &id, 10_000).unwrap();
+        assert_eq!(progress.goal, None);
+        assert_eq!(progress.percent, None);
+        assert!(!progress.goal_met);
+    }
+
+    #[test]
+    fn session_progress_unknown_session_returns_not_found() {
+        let (_dir, path) = setup_session_test();
+
+        let result = session_progress(&path, "nope", 0);
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    // ── delete_session ──────────────────────────────────────────────
+
+    #[test]
+    fn delete_session_removes_only_that_session() {
+        let (_dir, path) = setup_session_test();
+        write_test_sessions(
+            &path,
+            vec![
+                session_at("2026-02-03T09:00:00Z", 300, 20.0),
+                session_at("2026-02-04T09:00:00Z", 200, 15.0),
+            ],
+        );
+
+        delete_session(&path, "2026-02-03T09:00:00Z").unwrap();
+
+        let sessions = get_sessions(&path, None, None).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].id, "2026-02-04T09:00:00Z");
+    }
+
+    #[test]
+    fn delete_session_nonexistent_returns_not_found() {
+        let (_dir, path) = setup_session_test();
+        write_test_sessions(&path, vec![session_at("2026-02-03T09:00:00Z", 300, 20.0)]);
+
+        let result = delete_session(&path, "2026-01-01T00:00:00Z");
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+        assert_eq!(load_sessions(&path).unwrap().sessions.len(), 1);
+    }
+
+    #[test]
+    fn delete_session_updates_stats() {
+        let (_dir, path) = setup_session_test();
+        let keep = start_session(&path, "ch-1", None).unwrap();
+        end_session(&path, &keep, 400).unwrap();
+        let mistake = session_days_ago(0, 9000);
+        let mistake_id = mistake.id.clone();
+        let mut data = load_sessions(&path).unwrap();
+        data.sessions.push(mistake);
+        save_sessions(&path, &data).unwrap();
+        assert_eq!(get_session_stats(&path).unwrap().total_words, 9400);
+
+        delete_session(&path, &mistake_id).unwrap();
+
+        let stats = get_session_stats(&path).unwrap();
+        assert_eq!(stats.total_sessions, 1);
+        assert_eq!(stats.total_words, 400);
+        assert_eq!(stats.best_day_words, 400);
+    }
+
+    // ── amend_session ───────────────────────────────────────────────
+
+    #[test]
+    fn amend_session_word_count_keeps_timestamps() {
+        let (_dir, path) = setup_session_test();
+        let id = start_session(&path, "ch-1", None).unwrap();
+        end_session(&path, &id, 400).unwrap();
+        let before = load_sessions(&path).unwrap().sessions[0].clone();
+
+        let amended = amend_session(&path, &id, Some(650), None).unwrap();
+
+        assert_eq!(amended.words_written, 650);
+        let after = &load_sessions(&path).unwrap().sessions[0];
+        assert_eq!(after.words_written, 650);
+        assert_eq!(after.chapter_slug, "ch-1");
+        assert_eq!(after.start, before.start);
+        assert_eq!(after.end, before.end);
+        assert_eq!(after.duration_minutes, before.duration_minutes);
+    }
+
+    #[test]
+    fn amend_session_chapter() {
+        let (_dir, path) = setup_session_test();
+        let id = start_session(&path, "ch-1", None).unwrap();
+        end_session(&path, &id, 400).unwrap();
+
+        amend_session(&path, &id, None, Some("ch-2".to_string())).unwrap();
+
+        let after = &load_sessions(&path).unwrap().sessions[0];
+        assert_eq!(after.chapter_slug, "ch-2");
+        assert_eq!(after.words_written, 400);
+    }
+
+    #[test]
+    fn amend_session_rejects_open_session() {
+        let (_dir, path) = setup_session_test();
+        let id = start_session(&path, "ch-1", None).unwrap();
+
+        let result = amend_session(&path, &id, Some(100), None);
+
+        match result {
+            Err(AppError::InvalidOperation(msg)) => assert!(msg.contains("still open")),
+            other => panic!("expected InvalidOperation, got {:?}", other),
+        }
+        assert_eq!(load_sessions(&path).unwrap().sessions[0].words_written, 0);
+    }
+
+    #[test]
+    fn amend_session_nonexistent_returns_not_found() {
+        let (_dir, path) = setup_session_test();
+
+        let result = amend_session(&path, "nope", Some(1), None);
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    // ── get_sessions ────────────────────────────────────────────────
+
+    #[test]
+    fn get_sessions_returns_all_when_no_filter() {
+        let (_dir, path) = setup_session_test();
+
+        start_session(&path, "chapter-1", None).unwrap();
+        start_session(&path, "chapter-2", None).unwrap();
+
+        let sessions = get_sessions(&path, None, None).unwrap();
+        assert_eq!(sessions.len(), 2);
+    }
+
+    #[test]
+    fn get_sessions_returns_empty_for_new_project() {
+        let (_dir, path) = setup_session_test();
+
+        let sessions = get_sessions(&path, None, None).unwrap();
+        assert!(sessions.is_empty());
+    }
+
+    #[test]
+    fn get_sessions_filters_by_date_range() {
+        let (_dir, path) = setup_session_test();
+
+        let sessions = vec![
+            WritingSession {
+                id: "2026-02-10T10:00:00Z".to_string(),
+                start: "2026-02-10T10:00:00Z".to_string(),
+                end: Some("2026-02-10T10:30:00Z".to_string()),
+                duration_minutes: Some(30.0),
+                words_written: 300,
+                ..base_session()
+            },
+            WritingSession {
+                id: "2026-02-12T10:00:00Z".to_string(),
+                start: "2026-02-12T10:00:00Z".to_string(),
+                end: Some("2026-02-12T10:30:00Z".to_string()),
+                duration_minutes: Some(30.0),
+                words_written: 500,
+                ..base_session()
+            },
+            WritingSession {
+                id: "2026-02-14T10:00:00Z".to_string(),
+                start: "2026-02-14T10:00:00Z".to_string(),
+                end: Some("2026-02-14T10:30:00Z".to_string()),
+                duration_minutes: Some(30.0),
+                words_written: 700,
+                chapter_slug: "chapter-2".to_string(),
+                ..base_session()
+            },
+        ];
+        write_test_sessions(&path, sessions);
+
+        // Filter: only Feb 11-13 (should get the Feb 12 session)
+        let filtered = get_sessions(&path, Some("2026-02-11"), Some("2026-02-13")).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].words_written, 500);
+    }
+
+    #[test]
+    fn get_sessions_filters_with_only_from() {
+        let (_dir, path) = setup_session_test();
+
+        let sessions = vec![
+            WritingSession {
+                id: "2026-02-10T10:00:00Z".to_string(),
+                start: "2026-02-10T10:00:00Z".to_string(),
+                words_written: 300,
+                chapter_slug: "ch-1".to_string(),
+                ..base_session()
+            },
+            WritingSession {
+                id: "2026-02-14T10:00:00Z".to_string(),
+                start: "2026-02-14T10:00:00Z".to_string(),
                 words_written: 700,
                 chapter_slug: "ch-2".to_string(),
-                sprint_goal: None,
+                ..base_session()
+            },
+        ];
+        write_test_sessions(&path, sessions);
+
+        let filtered = get_sessions(&path, Some("2026-02-12"), None).unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].words_written, 700);
+    }
+
+    #[test]
+    fn get_sessions_filters_with_only_to() {
+        let (_dir, path) = setup_session_test();
+
+        let sessions = vec![
+            WritingSession {
+                id: "2026-02-10T10:00:00Z".to_string(),
+                start: "2026-02-10T10:00:00Z".to_string(),
+                words_written: 300,
+                chapter_slug: "ch-1".to_string(),
+                ..base_session()
+            },
+            WritingSession {
+                id: "2026-02-14T10:00:00Z".to_string(),
+                start: "2026-02-14T10:00:00Z".to_string(),
+                words_written: 700,
+                chapter_slug: "ch-2".to_string(),
+                ..base_session()
             },
         ];
         write_test_sessions(&path, sessions);
@@ -574,7 +1298,7 @@ mod tests {
 
     #[test]
     fn stats_with_zero_sessions() {
-        let stats = calculate_stats(&[]);
+        let stats = calculate_stats(&[], &SessionsConfig::default());
 
         assert_eq!(stats.total_sessions, 0);
         assert_eq!(stats.total_words, 0);
@@ -598,10 +1322,10 @@ mod tests {
             duration_minutes: Some(25.0),
             words_written: 500,
             chapter_slug: "ch-1".to_string(),
-            sprint_goal: None,
+            ..base_session()
         }];
 
-        let stats = calculate_stats(&sessions);
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
 
         assert_eq!(stats.total_sessions, 1);
         assert_eq!(stats.total_words, 500);
@@ -628,12 +1352,12 @@ mod tests {
                     duration_minutes: Some(25.0),
                     words_written: 400,
                     chapter_slug: "ch-1".to_string(),
-                    sprint_goal: None,
+                    ..base_session()
                 }
             })
             .collect();
 
-        let stats = calculate_stats(&sessions);
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
 
         assert_eq!(stats.total_sessions, 5);
         assert_eq!(stats.total_words, 2000);
@@ -659,16 +1383,15 @@ mod tests {
                 WritingSession {
                     id: dt.to_rfc3339(),
                     start: dt.to_rfc3339(),
-                    end: None,
                     duration_minutes: Some(25.0),
                     words_written: 300,
                     chapter_slug: "ch-1".to_string(),
-                    sprint_goal: None,
+                    ..base_session()
                 }
             })
             .collect();
 
-        let stats = calculate_stats(&sessions);
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
 
         assert_eq!(stats.current_streak, 2); // today + yesterday
         assert_eq!(stats.longest_streak, 2); // the gap breaks it
@@ -691,16 +1414,15 @@ mod tests {
                 WritingSession {
                     id: dt.to_rfc3339(),
                     start: dt.to_rfc3339(),
-                    end: None,
                     duration_minutes: Some(25.0),
                     words_written: 200,
                     chapter_slug: "ch-1".to_string(),
-                    sprint_goal: None,
+                    ..base_session()
                 }
             })
             .collect();
 
-        let stats = calculate_stats(&sessions);
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
 
         assert_eq!(stats.longest_streak, 4);
     }
@@ -711,33 +1433,30 @@ mod tests {
             WritingSession {
                 id: "2026-02-10T10:00:00Z".to_string(),
                 start: "2026-02-10T10:00:00Z".to_string(),
-                end: None,
                 duration_minutes: Some(25.0),
                 words_written: 300,
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
             WritingSession {
                 id: "2026-02-10T14:00:00Z".to_string(),
                 start: "2026-02-10T14:00:00Z".to_string(),
-                end: None,
                 duration_minutes: Some(25.0),
                 words_written: 400,
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
             WritingSession {
                 id: "2026-02-11T10:00:00Z".to_string(),
                 start: "2026-02-11T10:00:00Z".to_string(),
-                end: None,
                 duration_minutes: Some(25.0),
                 words_written: 500,
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
         ];
 
-        let stats = calculate_stats(&sessions);
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
 
         // Feb 10 had 300+400=700 total, Feb 11 had 500
         assert_eq!(stats.best_day_words, 700);
@@ -765,7 +1484,7 @@ mod tests {
                 duration_minutes: Some(30.0),
                 words_written: 500,
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
             WritingSession {
                 id: "2026-02-11T10:00:00Z".to_string(),
@@ -775,6 +1494,7 @@ mod tests {
                 words_written: 1000,
                 chapter_slug: "ch-2".to_string(),
                 sprint_goal: Some(800),
+                ..base_session()
             },
         ];
         write_test_sessions(&path, sessions);
@@ -797,8 +1517,8 @@ mod tests {
             end: Some("2026-02-14T11:00:00+00:00".to_string()),
             duration_minutes: Some(30.0),
             words_written: 847,
-            chapter_slug: "chapter-1".to_string(),
             sprint_goal: Some(500),
+            ..base_session()
         };
 
         write_test_sessions(&path, vec![session.clone()]);
@@ -830,14 +1550,12 @@ mod tests {
         let (_dir, path) = setup_session_test();
 
         let data = SessionsData {
+            config: SessionsConfig::default(),
             sessions: vec![WritingSession {
                 id: "test-id".to_string(),
                 start: "2026-02-14T10:00:00Z".to_string(),
-                end: None,
-                duration_minutes: None,
-                words_written: 0,
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             }],
         };
 
@@ -860,14 +1578,13 @@ mod tests {
         let sessions = vec![WritingSession {
             id: dt.to_rfc3339(),
             start: dt.to_rfc3339(),
-            end: None,
             duration_minutes: Some(25.0),
             words_written: 300,
             chapter_slug: "ch-1".to_string(),
-            sprint_goal: None,
+            ..base_session()
         }];
 
-        let stats = calculate_stats(&sessions);
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
         assert_eq!(stats.current_streak, 0);
         assert_eq!(stats.longest_streak, 1);
     }
@@ -889,16 +1606,15 @@ mod tests {
                 WritingSession {
                     id: dt.to_rfc3339(),
                     start: dt.to_rfc3339(),
-                    end: None,
                     duration_minutes: Some(25.0),
                     words_written: 300,
                     chapter_slug: "ch-1".to_string(),
-                    sprint_goal: None,
+                    ..base_session()
                 }
             })
             .collect();
 
-        let stats = calculate_stats(&sessions);
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
         // Should count from yesterday backwards
         assert_eq!(stats.current_streak, 2);
     }
@@ -913,24 +1629,22 @@ mod tests {
             WritingSession {
                 id: dt1.to_rfc3339(),
                 start: dt1.to_rfc3339(),
-                end: None,
                 duration_minutes: Some(25.0),
                 words_written: 300,
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
             WritingSession {
                 id: dt2.to_rfc3339(),
                 start: dt2.to_rfc3339(),
-                end: None,
                 duration_minutes: Some(25.0),
                 words_written: 400,
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
         ];
 
-        let stats = calculate_stats(&sessions);
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
         assert_eq!(stats.current_streak, 1);
         assert_eq!(stats.longest_streak, 1);
     }
@@ -949,7 +1663,7 @@ mod tests {
                 duration_minutes: Some(30.0),
                 words_written: 300,
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
             WritingSession {
                 id: "2026-02-14T10:00:00Z".to_string(),
@@ -958,7 +1672,7 @@ mod tests {
                 duration_minutes: Some(30.0),
                 words_written: 700,
                 chapter_slug: "ch-2".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
         ];
         write_test_sessions(&path, sessions);
@@ -986,7 +1700,7 @@ mod tests {
             duration_minutes: Some(60.0),
             words_written: 500,
             chapter_slug: "ch-1".to_string(),
-            sprint_goal: None,
+            ..base_session()
         }];
         write_test_sessions(&path, sessions);
 
@@ -1015,7 +1729,7 @@ mod tests {
                 duration_minutes: Some(240.0),
                 words_written: u32::MAX, // ~4.29 billion
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
             WritingSession {
                 id: "2026-02-11T10:00:00Z".to_string(),
@@ -1024,11 +1738,11 @@ mod tests {
                 duration_minutes: Some(240.0),
                 words_written: 1000,
                 chapter_slug: "ch-2".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
         ];
 
-        let stats = calculate_stats(&sessions);
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
 
         // total_words is u64 so it should not overflow
         assert_eq!(stats.total_words, u32::MAX as u64 + 1000);
@@ -1046,11 +1760,7 @@ mod tests {
         let session = WritingSession {
             id: "2026-02-14T10:30:00+00:00".to_string(),
             start: "2026-02-14T10:30:00+00:00".to_string(),
-            end: None,
-            duration_minutes: None,
-            words_written: 0,
-            chapter_slug: "chapter-1".to_string(),
-            sprint_goal: None,
+            ..base_session()
         };
 
         write_test_sessions(&path, vec![session.clone()]);
@@ -1070,7 +1780,7 @@ mod tests {
     #[test]
     fn calculate_current_streak_empty_dates() {
         let today = Utc::now().date_naive();
-        assert_eq!(calculate_current_streak(&[], today), 0);
+        assert_eq!(calculate_current_streak(&[], today, 1), 0);
     }
 
     #[test]
@@ -1117,7 +1827,7 @@ mod tests {
             today - chrono::Duration::days(1),
             today,
         ];
-        assert_eq!(calculate_current_streak(&dates, today), 3);
+        assert_eq!(calculate_current_streak(&dates, today, 1), 3);
     }
 
     #[test]
@@ -1129,7 +1839,7 @@ mod tests {
             today - chrono::Duration::days(1),
         ];
         // Should count from yesterday backwards (grace period)
-        assert_eq!(calculate_current_streak(&dates, today), 2);
+        assert_eq!(calculate_current_streak(&dates, today, 1), 2);
     }
 
     #[test]
@@ -1140,7 +1850,69 @@ mod tests {
             today - chrono::Duration::days(3),
             today - chrono::Duration::days(2),
         ];
-        assert_eq!(calculate_current_streak(&dates, today), 0);
+        assert_eq!(calculate_current_streak(&dates, today, 1), 0);
+    }
+
+    #[test]
+    fn calculate_current_streak_grace_zero_requires_today() {
+        let today = Utc::now().date_naive();
+        let yesterday_run = vec![
+            today - chrono::Duration::days(2),
+            today - chrono::Duration::days(1),
+        ];
+        assert_eq!(calculate_current_streak(&yesterday_run, today, 0), 0);
+
+        let today_run = vec![today - chrono::Duration::days(1), today];
+        assert_eq!(calculate_current_streak(&today_run, today, 0), 2);
+    }
+
+    #[test]
+    fn calculate_current_streak_grace_two_spans_a_weekend() {
+        // Last sessions three and four days ago: two missed days are allowed
+        let today = Utc::now().date_naive();
+        let dates = vec![
+            today - chrono::Duration::days(4),
+            today - chrono::Duration::days(3),
+        ];
+        assert_eq!(calculate_current_streak(&dates, today, 2), 0);
+        assert_eq!(calculate_current_streak(&dates, today, 3), 2);
+
+        let dates = vec![
+            today - chrono::Duration::days(4),
+            today - chrono::Duration::days(3),
+            today - chrono::Duration::days(2),
+        ];
+        assert_eq!(calculate_current_streak(&dates, today, 1), 0);
+        assert_eq!(calculate_current_streak(&dates, today, 2), 3);
+    }
+
+    #[test]
+    fn calculate_current_streak_grace_does_not_bridge_earlier_gaps() {
+        // The grace only applies before the most recent session day
+        let today = Utc::now().date_naive();
+        let dates = vec![
+            today - chrono::Duration::days(5),
+            today - chrono::Duration::days(2),
+            today - chrono::Duration::days(1),
+        ];
+        assert_eq!(calculate_current_streak(&dates, today, 2), 2);
+    }
+
+    #[test]
+    fn set_streak_grace_days_persists_and_feeds_stats() {
+        let (_dir, path) = setup_session_test();
+        write_test_sessions(
+            &path,
+            vec![session_days_ago(3, 400), session_days_ago(2, 400)],
+        );
+        assert_eq!(get_session_stats(&path).unwrap().current_streak, 0);
+
+        set_streak_grace_days(&path, 2).unwrap();
+
+        let data = load_sessions(&path).unwrap();
+        assert_eq!(data.config.streak_grace_days, 2);
+        assert_eq!(data.sessions.len(), 2);
+        assert_eq!(get_session_stats(&path).unwrap().current_streak, 2);
     }
 
     // ── Duplicate dates in streak ─────────────────────────────────
@@ -1167,20 +1939,16 @@ mod tests {
             WritingSession {
                 id: "2026-02-10T00:00:00Z".to_string(),
                 start: "2026-02-10T00:00:00Z".to_string(),
-                end: None,
-                duration_minutes: None,
                 words_written: 100,
                 chapter_slug: "ch-1".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
             WritingSession {
                 id: "2026-02-12T23:59:59Z".to_string(),
                 start: "2026-02-12T23:59:59Z".to_string(),
-                end: None,
-                duration_minutes: None,
                 words_written: 200,
                 chapter_slug: "ch-2".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
         ];
         write_test_sessions(&path, sessions);
@@ -1203,17 +1971,14 @@ mod tests {
                 end: Some("2026-02-10T10:30:00Z".to_string()),
                 duration_minutes: Some(30.0),
                 words_written: 500,
-                chapter_slug: "chapter-1".to_string(),
                 sprint_goal: Some(600),
+                ..base_session()
             },
             WritingSession {
                 id: "2026-02-11T09:00:00Z".to_string(),
                 start: "2026-02-11T09:00:00Z".to_string(),
-                end: None,
-                duration_minutes: None,
-                words_written: 0,
                 chapter_slug: "chapter-2".to_string(),
-                sprint_goal: None,
+                ..base_session()
             },
         ];
 
@@ -1227,4 +1992,493 @@ mod tests {
         assert!(loaded.sessions[1].end.is_none());
         assert!(loaded.sessions[1].sprint_goal.is_none());
     }
+
+    // ── export_sessions_csv ───────────────────────────────────────
+
+    #[test]
+    fn export_csv_empty_range_has_only_header() {
+        let (_dir, path) = setup_session_test();
+
+        let sessions = vec![WritingSession {
+            id: "2026-02-10T10:00:00Z".to_string(),
+            start: "2026-02-10T10:00:00Z".to_string(),
+            end: Some("2026-02-10T10:30:00Z".to_string()),
+            duration_minutes: Some(30.0),
+            words_written: 500,
+            chapter_slug: "ch-1".to_string(),
+            ..base_session()
+        }];
+        write_test_sessions(&path, sessions);
+
+        let csv = export_sessions_csv(&path, Some("2026-03-01"), Some("2026-03-31")).unwrap();
+        assert_eq!(
+            csv,
+            "id,start,end,duration_minutes,words_written,chapter_slug,sprint_goal\n"
+        );
+    }
+
+    #[test]
+    fn export_csv_multiple_rows() {
+        let (_dir, path) = setup_session_test();
+
+        let sessions = vec![
+            WritingSession {
+                id: "2026-02-10T10:00:00Z".to_string(),
+                start: "2026-02-10T10:00:00Z".to_string(),
+                end: Some("2026-02-10T10:30:00Z".to_string()),
+                duration_minutes: Some(30.0),
+                words_written: 500,
+                chapter_slug: "ch-1".to_string(),
+                sprint_goal: Some(400),
+                ..base_session()
+            },
+            WritingSession {
+                id: "2026-02-11T09:00:00Z".to_string(),
+                start: "2026-02-11T09:00:00Z".to_string(),
+                chapter_slug: "ch-2".to_string(),
+                ..base_session()
+            },
+        ];
+        write_test_sessions(&path, sessions);
+
+        let csv = export_sessions_csv(&path, None, None).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            "2026-02-10T10:00:00Z,2026-02-10T10:00:00Z,2026-02-10T10:30:00Z,30.00,500,ch-1,400"
+        );
+        assert_eq!(
+            lines[2],
+            "2026-02-11T09:00:00Z,2026-02-11T09:00:00Z,,,0,ch-2,"
+        );
+    }
+
+    #[test]
+    fn export_csv_quotes_fields_with_commas_and_quotes() {
+        let (_dir, path) = setup_session_test();
+
+        let sessions = vec![WritingSession {
+            id: "2026-02-10T10:00:00Z".to_string(),
+            start: "2026-02-10T10:00:00Z".to_string(),
+            words_written: 10,
+            chapter_slug: "one, \"two\"".to_string(),
+            ..base_session()
+        }];
+        write_test_sessions(&path, sessions);
+
+        let csv = export_sessions_csv(&path, None, None).unwrap();
+        let row = csv.lines().nth(1).unwrap();
+        assert!(row.ends_with(",10,\"one, \"\"two\"\"\","), "got: {}", row);
+    }
+
+    #[test]
+    fn csv_field_leaves_plain_values_unquoted() {
+        assert_eq!(csv_field("chapter-1"), "chapter-1");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+
+    // ── pause_session / resume_session ────────────────────────────
+
+    /// Shift a stored session timestamp field back by the given number of minutes.
+    fn backdate(project_path: &str, session_id: &str, field: &str, minutes: i64) {
+        let mut data = load_sessions(project_path).unwrap();
+        let session = data
+            .sessions
+            .iter_mut()
+            .find(|s| s.id == session_id)
+            .unwrap();
+        let ts = (Utc::now() - chrono::Duration::minutes(minutes)).to_rfc3339();
+        match field {
+            "start" => session.start = ts,
+            "paused_at" => session.paused_at = Some(ts),
+            _ => unreachable!(),
+        }
+        save_sessions(project_path, &data).unwrap();
+    }
+
+    #[test]
+    fn pause_resume_end_excludes_paused_span() {
+        let (_dir, path) = setup_session_test();
+
+        let id = start_session(&path, "ch-1", None).unwrap();
+        backdate(&path, &id, "start", 60);
+
+        pause_session(&path, &id).unwrap();
+        backdate(&path, &id, "paused_at", 40);
+        resume_session(&path, &id).unwrap();
+
+        let data = load_sessions(&path).unwrap();
+        assert!(data.sessions[0].paused_at.is_none());
+        assert!((data.sessions[0].paused_seconds - 2400.0).abs() < 5.0);
+
+        end_session(&path, &id, 400).unwrap();
+
+        let data = load_sessions(&path).unwrap();
+        let duration = data.sessions[0].duration_minutes.unwrap();
+        assert!(
+            (duration - 20.0).abs() < 0.1,
+            "Expected ~20 active minutes, got {}",
+            duration
+        );
+    }
+
+    #[test]
+    fn end_session_while_paused_excludes_open_pause() {
+        let (_dir, path) = setup_session_test();
+
+        let id = start_session(&path, "ch-1", None).unwrap();
+        backdate(&path, &id, "start", 30);
+        pause_session(&path, &id).unwrap();
+        backdate(&path, &id, "paused_at", 10);
+
+        end_session(&path, &id, 100).unwrap();
+
+        let data = load_sessions(&path).unwrap();
+        let session = &data.sessions[0];
+        assert!(session.paused_at.is_none());
+        assert!((session.duration_minutes.unwrap() - 20.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn pause_session_twice_errors() {
+        let (_dir, path) = setup_session_test();
+
+        let id = start_session(&path, "ch-1", None).unwrap();
+        pause_session(&path, &id).unwrap();
+        let result = pause_session(&path, &id);
+        assert!(matches!(result, Err(AppError::InvalidOperation(_))));
+    }
+
+    #[test]
+    fn resume_session_not_paused_errors() {
+        let (_dir, path) = setup_session_test();
+
+        let id = start_session(&path, "ch-1", None).unwrap();
+        let result = resume_session(&path, &id);
+        assert!(matches!(result, Err(AppError::InvalidOperation(_))));
+    }
+
+    #[test]
+    fn pause_session_after_end_errors() {
+        let (_dir, path) = setup_session_test();
+
+        let id = start_session(&path, "ch-1", None).unwrap();
+        end_session(&path, &id, 10).unwrap();
+        let result = pause_session(&path, &id);
+        assert!(matches!(result, Err(AppError::InvalidOperation(_))));
+    }
+
+    #[test]
+    fn pause_session_nonexistent_returns_not_found() {
+        let (_dir, path) = setup_session_test();
+
+        let result = pause_session(&path, "nonexistent");
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn stats_words_per_minute_uses_active_minutes() {
+        let sessions = vec![WritingSession {
+            id: "2026-02-10T10:00:00Z".to_string(),
+            start: "2026-02-10T10:00:00Z".to_string(),
+            end: Some("2026-02-10T11:00:00Z".to_string()),
+            duration_minutes: Some(20.0),
+            words_written: 500,
+            chapter_slug: "ch-1".to_string(),
+            paused_seconds: 2400.0,
+            ..base_session()
+        }];
+
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
+        assert_eq!(stats.words_per_minute, 25.0);
+    }
+
+    // ── Daily goal ────────────────────────────────────────────────
+
+    /// Build a completed session starting `days_ago` days before now.
+    fn session_days_ago(days_ago: i64, words: u32) -> WritingSession {
+        let start = (Utc::now() - chrono::Duration::days(days_ago)).to_rfc3339();
+        WritingSession {
+            id: start.clone(),
+            start,
+            duration_minutes: Some(30.0),
+            words_written: words,
+            chapter_slug: "ch-1".to_string(),
+            ..base_session()
+        }
+    }
+
+    #[test]
+    fn stats_daily_goal_met_today() {
+        let sessions = vec![session_days_ago(0, 300), session_days_ago(0, 250)];
+        let config = SessionsConfig {
+            daily_goal: Some(500),
+            streak_grace_days: 1,
+            timezone_offset_minutes: 0,
+        };
+
+        let stats = calculate_stats(&sessions, &config);
+        assert_eq!(stats.words_today, 550);
+        assert!(stats.goal_met_today);
+        assert!(!stats.streak_at_risk);
+    }
+
+    #[test]
+    fn stats_daily_goal_unmet_today() {
+        let sessions = vec![session_days_ago(0, 200)];
+        let config = SessionsConfig {
+            daily_goal: Some(500),
+            streak_grace_days: 1,
+            timezone_offset_minutes: 0,
+        };
+
+        let stats = calculate_stats(&sessions, &config);
+        assert_eq!(stats.words_today, 200);
+        assert!(!stats.goal_met_today);
+    }
+
+    #[test]
+    fn stats_goal_not_met_without_goal() {
+        let sessions = vec![session_days_ago(0, 2000)];
+
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
+        assert_eq!(stats.words_today, 2000);
+        assert!(!stats.goal_met_today);
+    }
+
+    #[test]
+    fn stats_streak_at_risk_when_nothing_written_today() {
+        let sessions = vec![session_days_ago(2, 400), session_days_ago(1, 400)];
+        let config = SessionsConfig {
+            daily_goal: Some(500),
+            streak_grace_days: 1,
+            timezone_offset_minutes: 0,
+        };
+
+        let stats = calculate_stats(&sessions, &config);
+        assert_eq!(stats.current_streak, 2);
+        assert_eq!(stats.words_today, 0);
+        assert!(stats.streak_at_risk);
+        assert!(!stats.goal_met_today);
+    }
+
+    #[test]
+    fn stats_no_streak_is_not_at_risk() {
+        let sessions = vec![session_days_ago(5, 400)];
+
+        let stats = calculate_stats(&sessions, &SessionsConfig::default());
+        assert_eq!(stats.current_streak, 0);
+        assert!(!stats.streak_at_risk);
+    }
+
+    #[test]
+    fn set_daily_goal_persists_and_feeds_stats() {
+        let (_dir, path) = setup_session_test();
+
+        let id = start_session(&path, "ch-1", None).unwrap();
+        end_session(&path, &id, 600).unwrap();
+        set_daily_goal(&path, Some(500)).unwrap();
+
+        let data = load_sessions(&path).unwrap();
+        assert_eq!(data.config.daily_goal, Some(500));
+        assert_eq!(data.sessions.len(), 1);
+
+        let stats = get_session_stats(&path).unwrap();
+        assert!(stats.goal_met_today);
+    }
+
+    // ── get_monthly_report ────────────────────────────────────────
+
+    /// Build a completed session at a fixed timestamp.
+    fn session_at(start: &str, words: u32, minutes: f64) -> WritingSession {
+        WritingSession {
+            id: start.to_string(),
+            start: start.to_string(),
+            duration_minutes: Some(minutes),
+            words_written: words,
+            chapter_slug: "ch-1".to_string(),
+            ..base_session()
+        }
+    }
+
+    #[test]
+    fn monthly_report_with_scattered_sessions() {
+        let (_dir, path) = setup_session_test();
+
+        write_test_sessions(
+            &path,
+            vec![
+                session_at("2026-01-31T22:00:00Z", 999, 10.0), // previous month
+                session_at("2026-02-03T09:00:00Z", 300, 20.0),
+                session_at("2026-02-03T18:00:00Z", 200, 15.0),
+                session_at("2026-02-14T10:00:00Z", 800, 40.0),
+                session_at("2026-02-28T23:00:00Z", 100, 5.0),
+                session_at("2026-03-01T08:00:00Z", 999, 10.0), // next month
+            ],
+        );
+
+        let report = get_monthly_report(&path, 2026, 2).unwrap();
+        assert_eq!(report.year, 2026);
+        assert_eq!(report.month, 2);
+        assert_eq!(report.days.len(), 28);
+        assert_eq!(report.days[0].date, "2026-02-01");
+        assert_eq!(report.days[2].words, 500);
+        assert_eq!(report.days[13].words, 800);
+        assert_eq!(report.days[27].words, 100);
+        assert_eq!(report.days[1].words, 0);
+        assert_eq!(report.total_words, 1400);
+        assert_eq!(report.total_minutes, 80.0);
+        assert_eq!(report.writing_days, 3);
+        assert_eq!(report.best_day_words, 800);
+        assert_eq!(report.best_day_date, Some("2026-02-14".to_string()));
+    }
+
+    #[test]
+    fn monthly_report_for_empty_month_is_all_zero() {
+        let (_dir, path) = setup_session_test();
+
+        write_test_sessions(&path, vec![session_at("2026-02-03T09:00:00Z", 300, 20.0)]);
+
+        let report = get_monthly_report(&path, 2026, 4).unwrap();
+        assert_eq!(report.days.len(), 30);
+        assert!(report.days.iter().all(|d| d.words == 0));
+        assert_eq!(report.total_words, 0);
+        assert_eq!(report.total_minutes, 0.0);
+        assert_eq!(report.writing_days, 0);
+        assert_eq!(report.best_day_words, 0);
+        assert!(report.best_day_date.is_none());
+    }
+
+    #[test]
+    fn monthly_report_handles_leap_february() {
+        let (_dir, path) = setup_session_test();
+
+        let report = get_monthly_report(&path, 2028, 2).unwrap();
+        assert_eq!(report.days.len(), 29);
+    }
+
+    #[test]
+    fn monthly_report_rejects_invalid_month() {
+        let (_dir, path) = setup_session_test();
+
+        let result = get_monthly_report(&path, 2026, 13);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn daily_word_map_sums_sessions_per_day() {
+        let sessions = vec![
+            session_at("2026-02-03T09:00:00Z", 300, 20.0),
+            session_at("2026-02-03T18:00:00Z", 200, 15.0),
+            session_at("not-a-date", 50, 5.0),
+        ];
+
+        let map = daily_word_map(&sessions, 0);
+        assert_eq!(map.len(), 1);
+        assert_eq!(
+            map.get(&NaiveDate::from_ymd_opt(2026, 2, 3).unwrap()),
+            Some(&500)
+        );
+    }
+
+    // ── Timezone offset ───────────────────────────────────────────
+
+    /// UTC timestamp of `hour:minute` local time `days_ago` days before the
+    /// local today, for a writer `offset_minutes` from UTC.
+    fn local_time_days_ago(offset_minutes: i32, days_ago: i64, hour: u32, minute: u32) -> String {
+        let offset = chrono::Duration::minutes(offset_minutes as i64);
+        let local = (local_today(offset_minutes) - chrono::Duration::days(days_ago))
+            .and_hms_opt(hour, minute, 0)
+            .unwrap();
+        (local - offset).and_utc().to_rfc3339()
+    }
+
+    #[test]
+    fn local_date_shifts_near_midnight_utc_to_previous_day() {
+        assert_eq!(
+            local_date("2026-03-02T02:00:00Z", -480),
+            NaiveDate::from_ymd_opt(2026, 3, 1)
+        );
+        assert_eq!(
+            local_date("2026-03-02T02:00:00Z", 0),
+            NaiveDate::from_ymd_opt(2026, 3, 2)
+        );
+        assert_eq!(
+            local_date("2026-03-01T20:00:00Z", 300),
+            NaiveDate::from_ymd_opt(2026, 3, 2)
+        );
+    }
+
+    #[test]
+    fn stats_best_day_uses_local_date() {
+        let sessions = vec![
+            session_at("2026-03-01T20:00:00Z", 300, 20.0),
+            session_at("2026-03-02T02:00:00Z", 500, 20.0),
+        ];
+
+        let utc = calculate_stats(&sessions, &SessionsConfig::default());
+        assert_eq!(utc.best_day_date, Some("2026-03-02".to_string()));
+        assert_eq!(utc.best_day_words, 500);
+
+        let config = SessionsConfig {
+            daily_goal: None,
+            streak_grace_days: 1,
+            timezone_offset_minutes: -480,
+        };
+        let local = calculate_stats(&sessions, &config);
+        assert_eq!(local.best_day_date, Some("2026-03-01".to_string()));
+        assert_eq!(local.best_day_words, 800);
+    }
+
+    #[test]
+    fn stats_streak_uses_local_dates() {
+        // Late-evening local sessions fall on the next UTC day
+        let offset = -480;
+        let sessions = vec![
+            session_at(&local_time_days_ago(offset, 2, 23, 30), 400, 20.0),
+            session_at(&local_time_days_ago(offset, 1, 23, 30), 600, 20.0),
+        ];
+        let config = SessionsConfig {
+            daily_goal: None,
+            streak_grace_days: 1,
+            timezone_offset_minutes: offset,
+        };
+
+        let stats = calculate_stats(&sessions, &config);
+        assert_eq!(stats.current_streak, 2);
+        assert_eq!(stats.words_today, 0);
+        assert!(stats.streak_at_risk);
+        let yesterday = local_today(offset) - chrono::Duration::days(1);
+        assert_eq!(
+            stats.best_day_date,
+            Some(yesterday.format("%Y-%m-%d").to_string())
+        );
+    }
+
+    #[test]
+    fn monthly_report_buckets_by_local_date() {
+        let (_dir, path) = setup_session_test();
+        write_test_sessions(&path, vec![session_at("2026-03-01T03:00:00Z", 400, 30.0)]);
+        set_timezone_offset(&path, -300).unwrap();
+
+        let february = get_monthly_report(&path, 2026, 2).unwrap();
+        assert_eq!(february.total_words, 400);
+        assert_eq!(february.days[27].words, 400);
+        let march = get_monthly_report(&path, 2026, 3).unwrap();
+        assert_eq!(march.total_words, 0);
+    }
+
+    #[test]
+    fn set_timezone_offset_rejects_out_of_range() {
+        let (_dir, path) = setup_session_test();
+
+        let result = set_timezone_offset(&path, 15 * 60);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert_eq!(
+            load_sessions(&path).unwrap().config.timezone_offset_minutes,
+            0
+        );
+    }
 }
@@ -0,0 +1,460 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::commands::manuscript::{get_chapter, get_manuscript_config};
+use crate::error::AppError;
+use crate::models::manuscript::{ChapterWordCount, TextStats, WordFrequency, WordUsage};
+
+/// How many words `chapter_text_stats` reports in `top_words` by default.
+const TOP_WORDS_COUNT: usize = 10;
+
+/// Common English function words left out of word-frequency lists.
+const STOPWORDS: &[&str] = &[
+    "a", "about", "after", "all", "an", "and", "any", "are", "as", "at", "be", "been", "but", "by",
+    "can", "could", "did", "do", "does", "for", "from", "had", "has", "have", "he", "her", "him",
+    "his", "i", "if", "in", "into", "is", "it", "its", "me", "my", "no", "not", "of", "on", "or",
+    "our", "out", "she", "so", "that", "the", "their", "them", "then", "there", "they", "this",
+    "to", "up", "was", "we", "were", "what", "when", "which", "who", "will", "with", "would",
+    "you", "your",
+];
+
+// ── Helpers ───────────────────────────────────────────────────────
+
+/// Reduce Markdown to its prose text.
+///
+/// Walks the `pulldown-cmark` events keeping only text and inline code, so
+/// markup, link targets and image URLs are dropped. Block elements are
+/// separated by blank lines so a heading never runs into the next paragraph.
+pub(crate) fn strip_markdown(markdown: &str) -> String {
+    use pulldown_cmark::{Event, Options, Parser, TagEnd};
+
+    let mut output = String::new();
+    for event in Parser::new_ext(markdown, Options::all()) {
+        match event {
+            Event::Text(text) | Event::Code(text) => output.push_str(&text),
+            Event::SoftBreak | Event::HardBreak => output.push(' '),
+            Event::End(
+                TagEnd::Paragraph
+                | TagEnd::Heading(_)
+                | TagEnd::Item
+                | TagEnd::BlockQuote(_)
+                | TagEnd::CodeBlock,
+            ) => output.push_str("\n\n"),
+            _ => {}
+        }
+    }
+    output
+}
+
+/// Split plain text into lowercased words, trimming surrounding punctuation.
+///
+/// Inner apostrophes and hyphens are kept ("don't", "well-read"); tokens
+/// without any letter or digit (dashes, ellipses) are skipped.
+pub(crate) fn tokenize_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|token| {
+            token
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .collect()
+}
+
+/// Count sentences as runs of text ending in `.`, `!` or `?` or a block break.
+fn count_sentences(text: &str) -> usize {
+    text.split("\n\n")
+        .flat_map(|block| block.split(['.', '!', '?']))
+        .filter(|sentence| sentence.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Approximate the syllables in a word by counting vowel groups.
+///
+/// A trailing silent "e" is not counted (except in "-le" endings), and every
+/// word has at least one syllable.
+fn count_syllables(word: &str) -> usize {
+    let letters: Vec<char> = word
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(char::to_lowercase)
+        .collect();
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for &c in &letters {
+        let vowel = is_vowel(c);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    let silent_e = letters.len() > 2
+        && letters.ends_with(&['e'])
+        && !letters.ends_with(&['l', 'e'])
+        && !is_vowel(letters[letters.len() - 2]);
+    if silent_e && count > 1 {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+/// Flesch reading ease: `206.835 - 1.015 * (words / sentences) - 84.6 * (syllables / words)`.
+fn flesch_reading_ease(words: &[String], sentences: usize) -> f64 {
+    if words.is_empty() || sentences == 0 {
+        return 0.0;
+    }
+    let syllables: usize = words.iter().map(|w| count_syllables(w)).sum();
+    let words_per_sentence = words.len() as f64 / sentences as f64;
+    let syllables_per_word = syllables as f64 / words.len() as f64;
+    206.835 - 1.015 * words_per_sentence - 84.6 * syllables_per_word
+}
+
+/// Compute readability and word-frequency statistics for Markdown prose.
+fn text_stats(markdown: &str, top_n: usize) -> TextStats {
+    let text = strip_markdown(markdown);
+    let words = tokenize_words(&text);
+    let sentences = count_sentences(&text);
+
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    for word in &words {
+        if !STOPWORDS.contains(&word.as_str()) {
+            *counts.entry(word.as_str()).or_insert(0) += 1;
+        }
+    }
+    let mut top_words: Vec<WordFrequency> = counts
+        .into_iter()
+        .map(|(word, count)| WordFrequency {
+            word: word.to_string(),
+            count,
+        })
+        .collect();
+    top_words.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+    top_words.truncate(top_n);
+
+    TextStats {
+        word_count: words.len() as u32,
+        sentence_count: sentences as u32,
+        avg_words_per_sentence: if sentences == 0 {
+            0.0
+        } else {
+            words.len() as f64 / sentences as f64
+        },
+        flesch_reading_ease: flesch_reading_ease(&words, sentences),
+        top_words,
+    }
+}
+
+// ── Commands ──────────────────────────────────────────────────────
+
+/// Readability and word-frequency statistics for one chapter.
+///
+/// The body is stripped of Markdown before analysis, so headings count as
+/// prose but link targets and formatting markers do not. `top_n` caps the
+/// `top_words` list and defaults to 10.
+#[tauri::command]
+pub fn chapter_text_stats(
+    project_path: String,
+    slug: String,
+    top_n: Option<usize>,
+) -> Result<TextStats, AppError> {
+    let chapter = get_chapter(project_path, slug)?;
+    Ok(text_stats(&chapter.body, top_n.unwrap_or(TOP_WORDS_COUNT)))
+}
+
+/// Words used more than `min_count` times across the whole manuscript.
+///
+/// Chapter bodies are Markdown-stripped and lowercased before counting.
+/// `stopwords` replaces the built-in English stopword list when given. Results
+/// are sorted by total (highest first), each with a per-chapter breakdown in
+/// manuscript order so clusters are easy to spot.
+#[tauri::command]
+pub fn overused_words(
+    project_path: String,
+    min_count: u32,
+    stopwords: Option<Vec<String>>,
+) -> Result<Vec<WordUsage>, AppError> {
+    let stopwords: HashSet<String> = match stopwords {
+        Some(words) => words.iter().map(|w| w.to_lowercase()).collect(),
+        None => STOPWORDS.iter().map(|w| w.to_string()).collect(),
+    };
+
+    let config = get_manuscript_config(project_path.clone())?;
+    let mut usage: BTreeMap<String, Vec<ChapterWordCount>> = BTreeMap::new();
+    for slug in &config.chapters {
+        let chapter = match get_chapter(project_path.clone(), slug.clone()) {
+            Ok(chapter) => chapter,
+            Err(AppError::NotFound(_)) => continue,
+            Err(e) => return Err(e),
+        };
+
+        let mut counts: HashMap<String, u32> = HashMap::new();
+        for word in tokenize_words(&strip_markdown(&chapter.body)) {
+            if !stopwords.contains(&word) {
+                *counts.entry(word).or_insert(0) += 1;
+            }
+        }
+        for (word, count) in counts {
+            usage.entry(word).or_default().push(ChapterWordCount {
+                slug: slug.clone(),
+                count,
+            });
+        }
+    }
+
+    let mut words: Vec<WordUsage> = usage
+        .into_iter()
+        .map(|(word, chapters)| WordUsage {
+            total: chapters.iter().map(|c| c.count).sum(),
+            word,
+            chapters,
+        })
+        .filter(|usage| usage.total > min_count)
+        .collect();
+    words.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.word.cmp(&b.word)));
+    Ok(words)
+}
+
+// ── Tests ─────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::manuscript::{create_chapter, save_chapter};
+    use crate::test_helpers::setup_test_dir;
+
+    /// Helper: create a chapter with the given body, returning its slug.
+    fn write_chapter(project_path: &str, title: &str, body: &str) -> String {
+        let created = create_chapter(project_path.to_string(), title.to_string()).unwrap();
+        save_chapter(
+            project_path.to_string(),
+            created.slug.clone(),
+            created.frontmatter,
+            body.to_string(),
+        )
+        .unwrap();
+        created.slug
+    }
+
+    // ── strip_markdown ────────────────────────────────────────────
+
+    #[test]
+    fn strip_markdown_drops_markup_and_link_targets() {
+        let text =
+            strip_markdown("# Title\n\nSome **bold** and `code` with [a link](https://x.io).");
+        assert!(text.contains("Title"));
+        assert!(text.contains("Some bold and code with a link."));
+        assert!(!text.contains("**"));
+        assert!(!text.contains("https"));
+    }
+
+    #[test]
+    fn tokenize_words_trims_punctuation_and_lowercases() {
+        let words = tokenize_words("\"Don't,\" she said — well-read and TIRED...");
+        assert_eq!(
+            words,
+            vec!["don't", "she", "said", "well-read", "and", "tired"]
+        );
+    }
+
+    #[test]
+    fn count_syllables_approximations() {
+        assert_eq!(count_syllables("cat"), 1);
+        assert_eq!(count_syllables("make"), 1);
+        assert_eq!(count_syllables("table"), 2);
+        assert_eq!(count_syllables("reading"), 2);
+        assert_eq!(count_syllables("organization"), 5);
+        assert_eq!(count_syllables("the"), 1);
+    }
+
+    // ── chapter_text_stats ────────────────────────────────────────
+
+    #[test]
+    fn chapter_text_stats_counts_known_paragraph() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        let slug = write_chapter(
+            &pp,
+            "Pets",
+            "The cat sat on the mat. The dog ran! Did the cat see the dog?\n",
+        );
+
+        let stats = chapter_text_stats(pp, slug, None).unwrap();
+        assert_eq!(stats.word_count, 15);
+        assert_eq!(stats.sentence_count, 3);
+        assert!((stats.avg_words_per_sentence - 5.0).abs() < f64::EPSILON);
+        assert_eq!(
+            stats.top_words[..2],
+            [
+                WordFrequency {
+                    word: "cat".to_string(),
+                    count: 2
+                },
+                WordFrequency {
+                    word: "dog".to_string(),
+                    count: 2
+                },
+            ]
+        );
+        assert!(stats.top_words.iter().all(|w| w.word != "the"));
+    }
+
+    #[test]
+    fn chapter_text_stats_flesch_bands() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        let simple = write_chapter(
+            &pp,
+            "Simple",
+            "The sun is hot. We go to the sea. I like to swim. The dog runs.\n",
+        );
+        let complex = write_chapter(
+            &pp,
+            "Complex",
+            "Notwithstanding considerable institutional opposition, the administration \
+             systematically implemented comprehensive organizational restructuring \
+             initiatives, fundamentally transforming interdepartmental communication \
+             methodologies.\n",
+        );
+
+        let simple_stats = chapter_text_stats(pp.clone(), simple, None).unwrap();
+        let complex_stats = chapter_text_stats(pp, complex, None).unwrap();
+        assert!(simple_stats.flesch_reading_ease > 90.0);
+        assert!(complex_stats.flesch_reading_ease < 30.0);
+    }
+
+    #[test]
+    fn chapter_text_stats_ignores_markdown() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        let slug = write_chapter(
+            &pp,
+            "Marked",
+            "## Storm\n\nThe *storm* broke over [the harbor](harbor.md).\n",
+        );
+
+        let stats = chapter_text_stats(pp, slug, None).unwrap();
+        assert_eq!(stats.word_count, 7);
+        assert_eq!(stats.sentence_count, 2);
+        assert_eq!(stats.top_words[0].word, "storm");
+        assert_eq!(stats.top_words[0].count, 2);
+    }
+
+    #[test]
+    fn chapter_text_stats_empty_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        let slug = write_chapter(&pp, "Blank", "");
+
+        let stats = chapter_text_stats(pp, slug, None).unwrap();
+        assert_eq!(stats.word_count, 0);
+        assert_eq!(stats.sentence_count, 0);
+        assert_eq!(stats.flesch_reading_ease, 0.0);
+        assert!(stats.top_words.is_empty());
+    }
+
+    #[test]
+    fn chapter_text_stats_respects_top_n() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        let body = "Alpha bravo charlie delta echo foxtrot golf hotel india juliet kilo lima.\n";
+        let slug = write_chapter(&pp, "Alphabet", body);
+
+        let stats = chapter_text_stats(pp.clone(), slug.clone(), None).unwrap();
+        assert_eq!(stats.top_words.len(), 10);
+
+        let stats = chapter_text_stats(pp, slug, Some(3)).unwrap();
+        let found: Vec<&str> = stats.top_words.iter().map(|w| w.word.as_str()).collect();
+        assert_eq!(found, vec!["alpha", "bravo", "charlie"]);
+    }
+
+    #[test]
+    fn chapter_text_stats_missing_chapter() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+
+        let result = chapter_text_stats(pp, "nope".to_string(), None);
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    // ── overused_words ────────────────────────────────────────────
+
+    /// Helper: two chapters that both lean on "suddenly" and "just".
+    fn overused_fixture(project_path: &str) {
+        write_chapter(
+            project_path,
+            "One",
+            "Suddenly the door opened. She just stood there. Suddenly, *suddenly*, it was over.\n",
+        );
+        write_chapter(
+            project_path,
+            "Two",
+            "He just waited. Suddenly the lights went out. It was just dark.\n",
+        );
+    }
+
+    #[test]
+    fn overused_words_reports_totals_and_per_chapter_counts() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        overused_fixture(&pp);
+
+        let words = overused_words(pp, 2, None).unwrap();
+        let found: Vec<&str> = words.iter().map(|w| w.word.as_str()).collect();
+        assert_eq!(found, vec!["suddenly", "just"]);
+
+        assert_eq!(words[0].total, 4);
+        assert_eq!(
+            words[0].chapters,
+            vec![
+                ChapterWordCount {
+                    slug: "one".to_string(),
+                    count: 3
+                },
+                ChapterWordCount {
+                    slug: "two".to_string(),
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(words[1].total, 3);
+        assert_eq!(words[1].chapters[0].count, 1);
+        assert_eq!(words[1].chapters[1].count, 2);
+    }
+
+    #[test]
+    fn overused_words_excludes_words_at_min_count() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        overused_fixture(&pp);
+
+        let words = overused_words(pp, 3, None).unwrap();
+        let found: Vec<&str> = words.iter().map(|w| w.word.as_str()).collect();
+        assert_eq!(found, vec!["suddenly"]);
+    }
+
+    #[test]
+    fn overused_words_excludes_default_stopwords() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        overused_fixture(&pp);
+
+        let words = overused_words(pp, 1, None).unwrap();
+        assert!(words.iter().all(|w| w.word != "the" && w.word != "it"));
+        assert!(words.iter().any(|w| w.word == "suddenly"));
+    }
+
+    #[test]
+    fn overused_words_custom_stopwords_replace_defaults() {
+        let dir = setup_test_dir();
+        let pp = dir.path().to_str().unwrap().to_string();
+        overused_fixture(&pp);
+
+        let words = overused_words(pp, 1, Some(vec!["Suddenly".to_string()])).unwrap();
+        let found: Vec<&str> = words.iter().map(|w| w.word.as_str()).collect();
+        assert!(!found.contains(&"suddenly"));
+        assert!(found.contains(&"just"));
+        assert!(found.contains(&"the"));
+    }
+}
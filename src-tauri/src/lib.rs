@@ -34,8 +34,18 @@ pub fn run() {
             commands::entity::get_entity,
             commands::entity::create_entity,
             commands::entity::save_entity,
+            commands::entity::save_entity_draft,
             commands::entity::delete_entity,
             commands::entity::rename_entity,
+            commands::entity::resolve_entity_reference,
+            commands::entity::find_referencing_entities,
+            commands::entity::list_entities_by_tag,
+            commands::entity::list_all_tags,
+            commands::entity::compare_spider_values,
+            commands::entity::import_entities_csv,
+            commands::entity::save_entity_template,
+            commands::entity::list_entity_templates,
+            commands::entity::create_entity_from_template,
             commands::manuscript::get_manuscript_config,
             commands::manuscript::save_manuscript_config,
             commands::manuscript::get_chapter,
@@ -43,6 +53,14 @@ pub fn run() {
             commands::manuscript::create_chapter,
             commands::manuscript::delete_chapter,
             commands::manuscript::reorder_chapters,
+            commands::manuscript::set_chapters_status,
+            commands::manuscript::manuscript_progress,
+            commands::import::import_docx,
+            commands::import::import_plaintext,
+            commands::export::export_scrivener,
+            commands::export::export_project_html,
+            commands::stats::chapter_text_stats,
+            commands::stats::overused_words,
             commands::manuscript::rename_chapter,
             commands::notes::get_notes_config,
             commands::notes::save_notes_config,
@@ -51,14 +69,35 @@ pub fn run() {
             commands::notes::create_note,
             commands::notes::delete_note,
             commands::notes::rename_note,
+            commands::notes::reorder_note,
+            commands::notes::default_note_palette,
             commands::search::search_project,
+            commands::search::search_content,
+            commands::search::replace_in_project,
             commands::search::resolve_wiki_link,
+            commands::search::resolve_wiki_target,
+            commands::search::find_broken_links,
+            commands::search::suggest_wiki_links,
             commands::search::find_backlinks,
+            commands::search::build_backlink_index,
+            commands::search::update_backlink_index_for_file,
+            commands::search::find_orphaned_entities,
             commands::sessions::start_session,
+            commands::sessions::pause_session,
+            commands::sessions::resume_session,
             commands::sessions::end_session,
+            commands::sessions::session_progress,
+            commands::sessions::delete_session,
+            commands::sessions::amend_session,
             commands::sessions::get_sessions,
             commands::sessions::get_session_stats,
+            commands::sessions::get_monthly_report,
+            commands::sessions::set_daily_goal,
+            commands::sessions::set_streak_grace_days,
+            commands::sessions::set_timezone_offset,
+            commands::sessions::export_sessions_csv,
             commands::compile::compile_manuscript,
+            commands::compile::compile_chapter,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -24,6 +24,54 @@ pub enum OutputFormat {
     Markdown,
     Html,
     PlainText,
+    Latex,
+}
+
+/// How chapter numbers are written in numbered headers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberStyle {
+    #[default]
+    Arabic,
+    Roman,
+    Words,
+}
+
+/// Numbering applied to chapter headers: the style and the number given to
+/// the first compiled chapter (e.g. 0 for a prologue).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterNumbering {
+    #[serde(default)]
+    pub style: NumberStyle,
+    #[serde(default = "default_numbering_start")]
+    pub start: i64,
+}
+
+fn default_numbering_start() -> i64 {
+    1
+}
+
+impl Default for ChapterNumbering {
+    fn default() -> Self {
+        Self {
+            style: NumberStyle::Arabic,
+            start: default_numbering_start(),
+        }
+    }
+}
+
+/// Which parts of each chapter are compiled, independent of output format.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BodyMode {
+    /// Header, optional synopsis and body.
+    #[default]
+    Full,
+    /// Header and synopsis only, for reviewing structure before drafting.
+    SynopsisOnly,
+    /// Chapter headers only.
+    TitlesOnly,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +85,27 @@ pub struct CompileConfig {
     pub output_format: OutputFormat,
     pub include_synopsis: bool,
     pub front_matter: String,
+    #[serde(default)]
+    pub chapter_numbering: ChapterNumbering,
+    #[serde(default)]
+    pub body_mode: BodyMode,
+    /// In HTML output, turn `[[links]]` to compiled chapters into in-page anchors.
+    #[serde(default)]
+    pub resolve_links: bool,
+    /// In HTML output, open each chapter's first paragraph with a drop cap.
+    #[serde(default)]
+    pub dropcaps: bool,
+    /// Replace `[[links]]` with the title of the chapter, note or entity they
+    /// name; unresolved links become their bare text.
+    #[serde(default)]
+    pub render_wiki_links: bool,
+    /// Words per printed page used for `CompileOutput::page_estimate`.
+    #[serde(default = "default_words_per_page")]
+    pub words_per_page: u32,
+}
+
+fn default_words_per_page() -> u32 {
+    250
 }
 
 impl Default for CompileConfig {
@@ -50,6 +119,12 @@ impl Default for CompileConfig {
             output_format: OutputFormat::Markdown,
             include_synopsis: false,
             front_matter: String::new(),
+            chapter_numbering: ChapterNumbering::default(),
+            body_mode: BodyMode::Full,
+            resolve_links: false,
+            dropcaps: false,
+            render_wiki_links: false,
+            words_per_page: default_words_per_page(),
         }
     }
 }
@@ -61,6 +136,13 @@ pub struct CompileOutput {
     pub format: OutputFormat,
     pub chapter_count: usize,
     pub word_count: usize,
+    /// Pages at `words_per_page`, rounded up.
+    #[serde(default)]
+    pub page_estimate: usize,
+    /// Pages in standard manuscript format (60-character lines, 25 lines per
+    /// page), rounded up. Only computed when compiling full chapter bodies.
+    #[serde(default)]
+    pub manuscript_page_estimate: Option<usize>,
 }
 
 #[cfg(test)]
@@ -81,6 +163,28 @@ mod tests {
         assert_eq!(config.output_format, OutputFormat::Markdown);
         assert!(!config.include_synopsis);
         assert_eq!(config.front_matter, "");
+        assert_eq!(config.chapter_numbering, ChapterNumbering::default());
+        assert_eq!(config.body_mode, BodyMode::Full);
+        assert!(!config.resolve_links);
+        assert!(!config.dropcaps);
+        assert!(!config.render_wiki_links);
+        assert_eq!(config.words_per_page, 250);
+    }
+
+    #[test]
+    fn test_compile_config_without_numbering_uses_default() {
+        let json = r#"{"title":"T","author":"A","includeTitlePage":false,
+            "chapterHeaderStyle":"numbered","chapterSeparator":"three_stars",
+            "outputFormat":"markdown","includeSynopsis":false,"frontMatter":""}"#;
+        let config: CompileConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.chapter_numbering.style, NumberStyle::Arabic);
+        assert_eq!(config.chapter_numbering.start, 1);
+        assert_eq!(config.body_mode, BodyMode::Full);
+        assert_eq!(config.words_per_page, 250);
+
+        let numbering: ChapterNumbering = serde_json::from_str(r#"{"style":"roman"}"#).unwrap();
+        assert_eq!(numbering.style, NumberStyle::Roman);
+        assert_eq!(numbering.start, 1);
     }
 
     #[test]
@@ -94,6 +198,12 @@ mod tests {
             output_format: OutputFormat::Html,
             include_synopsis: true,
             front_matter: "Dedication: To everyone.".to_string(),
+            chapter_numbering: ChapterNumbering::default(),
+            body_mode: BodyMode::Full,
+            resolve_links: false,
+            dropcaps: false,
+            words_per_page: 250,
+            render_wiki_links: false,
         };
 
         let json = serde_json::to_string(&config).expect("serialize");
@@ -119,6 +229,8 @@ mod tests {
             format: OutputFormat::Markdown,
             chapter_count: 1,
             word_count: 3,
+            page_estimate: 1,
+            manuscript_page_estimate: Some(1),
         };
 
         let json = serde_json::to_string(&output).expect("serialize");
@@ -184,6 +296,10 @@ mod tests {
             serde_json::to_string(&OutputFormat::PlainText).unwrap(),
             "\"plain_text\""
         );
+        assert_eq!(
+            serde_json::to_string(&OutputFormat::Latex).unwrap(),
+            "\"latex\""
+        );
     }
 
     #[test]
@@ -223,11 +339,15 @@ mod tests {
             format: OutputFormat::Markdown,
             chapter_count: 0,
             word_count: 0,
+            page_estimate: 0,
+            manuscript_page_estimate: None,
         };
         let json = serde_json::to_string(&output).unwrap();
 
         assert!(json.contains("\"chapterCount\""));
         assert!(json.contains("\"wordCount\""));
+        assert!(json.contains("\"pageEstimate\""));
+        assert!(json.contains("\"manuscriptPageEstimate\""));
         assert!(!json.contains("\"chapter_count\""));
         assert!(!json.contains("\"word_count\""));
     }
@@ -11,6 +11,9 @@ pub enum FieldType {
     Select,
     Date,
     Boolean,
+    /// Holds the slug of another entity, of the schema type named by
+    /// `EntityField::reference_schema`.
+    Reference,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +33,9 @@ pub struct EntityField {
     pub min: Option<f64>,
     #[serde(default)]
     pub max: Option<f64>,
+    /// Schema type a `Reference` field points at.
+    #[serde(default)]
+    pub reference_schema: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,6 +90,51 @@ pub struct EntityFrontmatter {
     pub fields: HashMap<String, serde_json::Value>,
 }
 
+/// Outcome of saving an entity: the entity as written plus any non-fatal warnings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveEntityResult {
+    pub entity: EntityInstance,
+    pub warnings: Vec<String>,
+}
+
+/// A single way in which an entity's fields break its schema.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationError {
+    pub field: String,
+    pub message: String,
+}
+
+/// Preset field values for creating new entities of one schema type.
+/// Stored at `templates/{schema_type}/{slug(name)}.yaml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityTemplate {
+    pub name: String,
+    pub schema_type: String,
+    #[serde(default)]
+    pub fields: HashMap<String, serde_json::Value>,
+}
+
+/// A CSV row that could not be imported, with the reasons why.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvRowError {
+    /// 1-based line number in the CSV, counting the header row.
+    pub line: usize,
+    pub title: String,
+    pub errors: Vec<ValidationError>,
+}
+
+/// Outcome of a bulk CSV import: what was created and which rows were skipped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CsvImportReport {
+    pub imported: Vec<EntitySummary>,
+    pub failed: Vec<CsvRowError>,
+}
+
 /// Lightweight summary of an entity instance (for listing).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -94,6 +145,14 @@ pub struct EntitySummary {
     pub tags: Vec<String>,
 }
 
+/// A tag and how many entities carry it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TagCount {
+    pub tag: String,
+    pub count: usize,
+}
+
 /// Full entity instance with body content.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -106,3 +165,32 @@ pub struct EntityInstance {
     pub fields: HashMap<String, serde_json::Value>,
     pub body: String,
 }
+
+// ── Spider Comparison Models ────────────────────────────────────
+
+/// An entity taking part in a spider-chart comparison.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparedEntity {
+    pub slug: String,
+    pub title: String,
+}
+
+/// One schema axis with each compared entity's value, in `SpiderComparison::entities` order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AxisComparison {
+    pub name: String,
+    pub min: f64,
+    pub max: f64,
+    pub values: Vec<f64>,
+}
+
+/// Spider values for several entities of one schema, ready to draw on a shared radar chart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpiderComparison {
+    pub schema_type: String,
+    pub entities: Vec<ComparedEntity>,
+    pub axes: Vec<AxisComparison>,
+}
@@ -1,11 +1,71 @@
 use serde::{Deserialize, Serialize};
 
+/// Where a chapter is in the writing process.
+///
+/// Stored as a snake_case string. Strings this version doesn't recognise are
+/// kept verbatim as `Unknown` instead of failing to parse the chapter.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "snake_case")]
+#[serde(from = "String", into = "String")]
 pub enum ChapterStatus {
+    Outline,
     Draft,
     Revised,
     Final,
+    Unknown(String),
+}
+
+impl ChapterStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ChapterStatus::Outline => "outline",
+            ChapterStatus::Draft => "draft",
+            ChapterStatus::Revised => "revised",
+            ChapterStatus::Final => "final",
+            ChapterStatus::Unknown(s) => s,
+        }
+    }
+
+    /// Whether a chapter may move from this status to `next`.
+    ///
+    /// An outline must be drafted before it can be revised or finalised, and
+    /// written chapters can't drop back to an outline except from draft.
+    /// Unknown statuses may be replaced by any known one, but never set.
+    pub fn can_transition_to(&self, next: &ChapterStatus) -> bool {
+        use ChapterStatus::*;
+        if self == next {
+            return true;
+        }
+        match (self, next) {
+            (_, Unknown(_)) => false,
+            (Unknown(_), _) => true,
+            (Outline, Draft) => true,
+            (Outline, _) => false,
+            (Draft, _) => true,
+            (Revised | Final, Outline) => false,
+            (Revised | Final, _) => true,
+        }
+    }
+}
+
+impl From<String> for ChapterStatus {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "outline" => ChapterStatus::Outline,
+            "draft" => ChapterStatus::Draft,
+            "revised" => ChapterStatus::Revised,
+            "final" => ChapterStatus::Final,
+            _ => ChapterStatus::Unknown(s),
+        }
+    }
+}
+
+impl From<ChapterStatus> for String {
+    fn from(status: ChapterStatus) -> Self {
+        match status {
+            ChapterStatus::Unknown(s) => s,
+            known => known.as_str().to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,3 +115,133 @@ pub struct ChapterContent {
     pub frontmatter: Chapter,
     pub body: String,
 }
+
+/// Word count against target for a single chapter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterProgress {
+    pub slug: String,
+    pub title: String,
+    pub words: u64,
+    #[serde(default)]
+    pub target_words: Option<u32>,
+    /// `words / target_words * 100`, not clamped; `None` without a target.
+    #[serde(default)]
+    pub percent_complete: Option<f64>,
+}
+
+/// Project-wide word-count progress rolled up from every chapter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManuscriptProgress {
+    pub total_words: u64,
+    pub total_target_words: u64,
+    /// `total_words / total_target_words * 100`; `None` when no chapter has a target.
+    #[serde(default)]
+    pub percent_complete: Option<f64>,
+    pub chapters: Vec<ChapterProgress>,
+}
+
+/// Outcome of setting the status of several chapters at once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterStatusUpdate {
+    /// Slugs whose status was set, in request order.
+    pub updated: Vec<String>,
+    /// Requested slugs with no chapter file.
+    pub not_found: Vec<String>,
+}
+
+/// How often a word appears in a piece of text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WordFrequency {
+    pub word: String,
+    pub count: u32,
+}
+
+/// Readability and word-frequency statistics for a chapter's prose.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextStats {
+    pub word_count: u32,
+    pub sentence_count: u32,
+    pub avg_words_per_sentence: f64,
+    /// Flesch reading ease; higher is easier (90+ very easy, below 30 very hard).
+    pub flesch_reading_ease: f64,
+    /// Most frequent non-stopword words, most frequent first.
+    pub top_words: Vec<WordFrequency>,
+}
+
+/// Occurrences of a word within one chapter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterWordCount {
+    pub slug: String,
+    pub count: u32,
+}
+
+/// A frequently used word with its manuscript-wide total and where it occurs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WordUsage {
+    pub word: String,
+    pub total: u32,
+    /// Chapters containing the word, in manuscript order.
+    pub chapters: Vec<ChapterWordCount>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chapter_status_serializes_snake_case() {
+        assert_eq!(
+            serde_yaml::to_string(&ChapterStatus::Outline).unwrap(),
+            "outline\n"
+        );
+        assert_eq!(
+            serde_json::to_string(&ChapterStatus::Revised).unwrap(),
+            "\"revised\""
+        );
+    }
+
+    #[test]
+    fn chapter_status_parses_known_values() {
+        for (raw, expected) in [
+            ("outline", ChapterStatus::Outline),
+            ("draft", ChapterStatus::Draft),
+            ("revised", ChapterStatus::Revised),
+            ("final", ChapterStatus::Final),
+        ] {
+            let status: ChapterStatus = serde_yaml::from_str(raw).unwrap();
+            assert_eq!(status, expected);
+        }
+    }
+
+    #[test]
+    fn chapter_status_keeps_unexpected_string() {
+        let yaml = "title: Old\nslug: old\nstatus: in-review\n";
+        let fm: ChapterFrontmatter = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(fm.status, ChapterStatus::Unknown("in-review".to_string()));
+
+        let round_trip = serde_yaml::to_string(&fm).unwrap();
+        assert!(round_trip.contains("status: in-review"));
+    }
+
+    #[test]
+    fn chapter_status_transitions() {
+        use ChapterStatus::*;
+        assert!(Outline.can_transition_to(&Draft));
+        assert!(!Outline.can_transition_to(&Revised));
+        assert!(!Outline.can_transition_to(&Final));
+        assert!(Draft.can_transition_to(&Final));
+        assert!(Draft.can_transition_to(&Outline));
+        assert!(Final.can_transition_to(&Revised));
+        assert!(!Final.can_transition_to(&Outline));
+        assert!(Unknown("wip".to_string()).can_transition_to(&Draft));
+        assert!(!Draft.can_transition_to(&Unknown("wip".to_string())));
+        assert!(Unknown("wip".to_string()).can_transition_to(&Unknown("wip".to_string())));
+    }
+}
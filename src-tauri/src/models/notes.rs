@@ -7,6 +7,14 @@ pub struct CorkboardPosition {
     pub y: f64,
 }
 
+/// A named preset color for corkboard cards.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteColor {
+    pub name: String,
+    pub hex: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NoteEntry {
@@ -14,6 +14,30 @@ pub struct WritingSession {
     pub chapter_slug: String,
     #[serde(default)]
     pub sprint_goal: Option<u32>,
+    /// Total seconds spent paused, excluded from `duration_minutes`.
+    #[serde(default)]
+    pub paused_seconds: f64,
+    /// When the current pause started, if the session is paused.
+    #[serde(default)]
+    pub paused_at: Option<String>,
+    /// Word count of `chapter_slug` when the session started, if it existed.
+    #[serde(default)]
+    pub start_word_count: Option<u32>,
+    /// Net change in the chapter's word count between the start and end
+    /// snapshots; negative after net deletions, which record 0 in
+    /// `words_written`.
+    #[serde(default)]
+    pub net_word_change: Option<i64>,
+    /// Gross words added to the chapter, summed over every save during the
+    /// session, so edits that cancel out still count.
+    #[serde(default)]
+    pub words_added: u32,
+    /// Gross words removed from the chapter, summed like `words_added`.
+    #[serde(default)]
+    pub words_removed: u32,
+    /// The chapter's word count at the last save seen by the session.
+    #[serde(default)]
+    pub last_word_count: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,11 +53,84 @@ pub struct SessionStats {
     pub monthly_average: f64,
     pub best_day_words: u32,
     pub best_day_date: Option<String>,
+    pub words_per_minute: f64,
+    pub words_today: u32,
+    pub goal_met_today: bool,
+    pub streak_at_risk: bool,
+}
+
+/// Live progress of a session towards its sprint goal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SprintProgress {
+    pub words: u32,
+    #[serde(default)]
+    pub goal: Option<u32>,
+    /// `words / goal * 100`, not clamped; `None` without a goal.
+    #[serde(default)]
+    pub percent: Option<f64>,
+    pub goal_met: bool,
+    /// Active minutes since the session started, excluding paused time.
+    pub elapsed_minutes: f64,
+}
+
+/// Words written on a single calendar day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyWords {
+    pub date: String,
+    pub words: u32,
+}
+
+/// Per-day breakdown and totals for one calendar month.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyReport {
+    pub year: i32,
+    pub month: u32,
+    pub days: Vec<DailyWords>,
+    pub total_words: u64,
+    pub total_minutes: f64,
+    pub writing_days: u32,
+    pub best_day_words: u32,
+    pub best_day_date: Option<String>,
+}
+
+/// Per-project session settings stored alongside the sessions list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionsConfig {
+    #[serde(default)]
+    pub daily_goal: Option<u32>,
+    /// Days without a session, counting back from today, that still leave the
+    /// current streak unbroken (0 = must write today, 2 = weekend grace).
+    #[serde(default = "default_streak_grace_days")]
+    pub streak_grace_days: u32,
+    /// Writer's offset from UTC in minutes (e.g. -480 for UTC-8), used to
+    /// decide which local day a session belongs to.
+    #[serde(default)]
+    pub timezone_offset_minutes: i32,
+}
+
+fn default_streak_grace_days() -> u32 {
+    1
+}
+
+impl Default for SessionsConfig {
+    fn default() -> Self {
+        Self {
+            daily_goal: None,
+            streak_grace_days: default_streak_grace_days(),
+            timezone_offset_minutes: 0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SessionsData {
+    #[serde(default)]
+    pub config: SessionsConfig,
     #[serde(default)]
     pub sessions: Vec<WritingSession>,
 }
@@ -52,6 +149,13 @@ mod tests {
             words_written: 847,
             chapter_slug: "chapter-1".to_string(),
             sprint_goal: Some(500),
+            paused_seconds: 0.0,
+            paused_at: None,
+            start_word_count: None,
+            net_word_change: None,
+            words_added: 0,
+            words_removed: 0,
+            last_word_count: None,
         };
 
         let yaml = serde_yaml::to_string(&session).unwrap();
@@ -85,6 +189,7 @@ chapterSlug: chapter-1
     #[test]
     fn sessions_data_yaml_round_trip() {
         let data = SessionsData {
+            config: SessionsConfig::default(),
             sessions: vec![
                 WritingSession {
                     id: "2026-02-14T10:30:00Z".to_string(),
@@ -94,6 +199,13 @@ chapterSlug: chapter-1
                     words_written: 847,
                     chapter_slug: "chapter-1".to_string(),
                     sprint_goal: None,
+                    paused_seconds: 0.0,
+                    paused_at: None,
+                    start_word_count: None,
+                    net_word_change: None,
+                    words_added: 0,
+                    words_removed: 0,
+                    last_word_count: None,
                 },
                 WritingSession {
                     id: "2026-02-15T09:00:00Z".to_string(),
@@ -103,6 +215,13 @@ chapterSlug: chapter-1
                     words_written: 0,
                     chapter_slug: "chapter-2".to_string(),
                     sprint_goal: Some(1000),
+                    paused_seconds: 0.0,
+                    paused_at: None,
+                    start_word_count: None,
+                    net_word_change: None,
+                    words_added: 0,
+                    words_removed: 0,
+                    last_word_count: None,
                 },
             ],
         };
@@ -120,6 +239,24 @@ chapterSlug: chapter-1
         let yaml = "sessions: []\n";
         let data: SessionsData = serde_yaml::from_str(yaml).unwrap();
         assert!(data.sessions.is_empty());
+        assert!(data.config.daily_goal.is_none());
+        assert_eq!(data.config.streak_grace_days, 1);
+    }
+
+    #[test]
+    fn sessions_data_reads_daily_goal_from_config() {
+        let yaml = "config:\n  dailyGoal: 750\nsessions: []\n";
+        let data: SessionsData = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(data.config.daily_goal, Some(750));
+        assert_eq!(data.config.streak_grace_days, 1);
+    }
+
+    #[test]
+    fn sessions_data_reads_streak_grace_days_from_config() {
+        let yaml = "config:\n  streakGraceDays: 2\nsessions: []\n";
+        let data: SessionsData = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(data.config.streak_grace_days, 2);
+        assert_eq!(data.config.timezone_offset_minutes, 0);
     }
 
     #[test]
@@ -135,6 +272,10 @@ chapterSlug: chapter-1
             monthly_average: 15000.0,
             best_day_words: 1200,
             best_day_date: Some("2026-02-10".to_string()),
+            words_per_minute: 0.0,
+            words_today: 0,
+            goal_met_today: false,
+            streak_at_risk: false,
         };
 
         let yaml = serde_yaml::to_string(&stats).unwrap();
@@ -159,6 +300,10 @@ chapterSlug: chapter-1
             monthly_average: 0.0,
             best_day_words: 0,
             best_day_date: None,
+            words_per_minute: 0.0,
+            words_today: 0,
+            goal_met_today: false,
+            streak_at_risk: false,
         };
 
         let yaml = serde_yaml::to_string(&stats).unwrap();
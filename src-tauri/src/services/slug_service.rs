@@ -9,6 +9,21 @@ pub fn slugify(title: &str) -> String {
     slug::slugify(title)
 }
 
+/// Return `base`, or `base-2`, `base-3`, … whichever is first not in `existing`.
+///
+/// Titles such as "Café" and "Cafe" slugify identically; this keeps them from
+/// landing on the same file.
+pub fn unique_slug(existing: &[String], base: &str) -> String {
+    let taken = |slug: &str| existing.iter().any(|s| s == slug);
+    let mut slug = base.to_string();
+    let mut n = 2;
+    while taken(&slug) {
+        slug = format!("{}-{}", base, n);
+        n += 1;
+    }
+    slug
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,4 +65,16 @@ mod tests {
     fn already_slug() {
         assert_eq!(slugify("already-a-slug"), "already-a-slug");
     }
+
+    #[test]
+    fn unique_slug_free_base_is_unchanged() {
+        let existing = vec!["other".to_string()];
+        assert_eq!(unique_slug(&existing, "cafe"), "cafe");
+    }
+
+    #[test]
+    fn unique_slug_appends_first_free_suffix() {
+        let existing = vec!["cafe".to_string(), "cafe-2".to_string()];
+        assert_eq!(unique_slug(&existing, "cafe"), "cafe-3");
+    }
 }
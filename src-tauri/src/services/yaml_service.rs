@@ -1,7 +1,8 @@
 use crate::error::AppError;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 /// Read and deserialize a YAML file.
 pub fn read_yaml<T: DeserializeOwned>(path: &Path) -> Result<T, AppError> {
@@ -17,12 +18,46 @@ pub fn read_yaml<T: DeserializeOwned>(path: &Path) -> Result<T, AppError> {
 }
 
 /// Serialize and write a value to a YAML file.
+///
+/// The write is atomic: see [`write_atomic`].
 pub fn write_yaml<T: Serialize>(path: &Path, value: &T) -> Result<(), AppError> {
     let content = serde_yaml::to_string(value)?;
+    write_atomic(path, content.as_bytes())
+}
+
+/// Temporary sibling used while atomically replacing `path`.
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.tmp", name))
+}
+
+/// Replace `path` with `contents` without ever leaving a half-written file.
+///
+/// The data goes to a temporary file in the same directory, is fsynced, and
+/// is then renamed over the target. If anything fails the original file is
+/// left untouched and the temporary file is removed.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), AppError> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)?;
     }
-    std::fs::write(path, content)?;
+
+    let tmp = temp_path(path);
+    let result = std::fs::File::create(&tmp)
+        .and_then(|mut file| {
+            file.write_all(contents)?;
+            file.sync_all()
+        })
+        .and_then(|()| std::fs::rename(&tmp, path));
+
+    if let Err(e) = result {
+        if tmp.is_file() {
+            let _ = std::fs::remove_file(&tmp);
+        }
+        return Err(AppError::Io(e));
+    }
     Ok(())
 }
 
@@ -70,4 +105,59 @@ mod tests {
         write_yaml(&path, &config).unwrap();
         assert!(path.exists());
     }
+
+    #[test]
+    fn write_is_byte_correct_and_leaves_no_temp_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, "name: old\ncount: 0\n").unwrap();
+        let config = Config {
+            name: "new".to_string(),
+            count: 7,
+        };
+
+        write_yaml(&path, &config).unwrap();
+
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(written, serde_yaml::to_string(&config).unwrap());
+        assert!(!temp_path(&path).exists());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn failed_write_leaves_original_intact() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.yaml");
+        let original = "name: original\ncount: 1\n";
+        std::fs::write(&path, original).unwrap();
+
+        // Occupy the temp path with a directory so the write fails midway.
+        std::fs::create_dir(temp_path(&path)).unwrap();
+        let config = Config {
+            name: "lost".to_string(),
+            count: 2,
+        };
+        let result = write_yaml(&path, &config);
+
+        assert!(matches!(result, Err(AppError::Io(_))));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+    }
+
+    #[test]
+    fn failed_serialization_leaves_original_intact() {
+        struct Unserializable;
+        impl Serialize for Unserializable {
+            fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+                Err(serde::ser::Error::custom("boom"))
+            }
+        }
+
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.yaml");
+        std::fs::write(&path, "name: original\n").unwrap();
+
+        assert!(write_yaml(&path, &Unserializable).is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "name: original\n");
+        assert!(!temp_path(&path).exists());
+    }
 }
@@ -1,5 +1,11 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { EntitySchema, SchemaSummary, EntityInstance, EntitySummary } from '$lib/types';
+import type {
+  EntitySchema,
+  SchemaSummary,
+  EntityInstance,
+  EntitySummary,
+  SaveEntityResult,
+} from '$lib/types';
 
 class EntityStore {
   schemaSummaries = $state<SchemaSummary[]>([]);
@@ -133,16 +139,26 @@ class EntityStore {
     }
   }
 
-  async saveEntity(projectPath: string, entity: EntityInstance): Promise<void> {
+  /**
+   * Save an entity. Drafts are written even when fields break the schema;
+   * the violations come back as warnings instead of an error.
+   */
+  async saveEntity(
+    projectPath: string,
+    entity: EntityInstance,
+    draft = false,
+  ): Promise<SaveEntityResult> {
     this.isLoading = true;
     this.error = null;
     try {
-      await invoke('save_entity', {
+      const result = await invoke<SaveEntityResult>(draft ? 'save_entity_draft' : 'save_entity', {
         projectPath,
         entity,
       });
-      this.currentEntity = entity;
+      // Spider values may have been clamped or dropped on save
+      this.currentEntity = result.entity;
       this.invalidateType(entity.schemaSlug);
+      return result;
     } catch (e) {
       this.error = String(e);
       throw e;
@@ -1,10 +1,11 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { NotesConfig, NoteContent, CorkboardPosition } from '$lib/types';
+import type { NotesConfig, NoteContent, NoteColor, CorkboardPosition } from '$lib/types';
 
 class NotesStore {
   config = $state<NotesConfig>({ notes: [] });
   noteContent = $state<Record<string, NoteContent>>({});
   activeNoteSlug = $state<string | null>(null);
+  palette = $state<NoteColor[]>([]);
   isLoading = $state(false);
   error = $state<string | null>(null);
 
@@ -26,6 +27,10 @@ class NotesStore {
     }
   }
 
+  async loadPalette(): Promise<void> {
+    this.palette = await invoke<NoteColor[]>('default_note_palette');
+  }
+
   async saveConfig(projectPath: string): Promise<void> {
     this.isLoading = true;
     this.error = null;
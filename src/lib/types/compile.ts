@@ -5,7 +5,19 @@ export type ChapterHeaderStyle = 'numbered' | 'titled' | 'numbered_and_titled' |
 export type ChapterSeparator = 'page_break' | 'three_stars' | 'horizontal_rule' | 'blank_lines';
 
 /** Matches Rust OutputFormat enum (serde snake_case) */
-export type OutputFormat = 'markdown' | 'html' | 'plain_text';
+export type OutputFormat = 'markdown' | 'html' | 'plain_text' | 'latex';
+
+/** Matches Rust NumberStyle enum (serde snake_case) */
+export type NumberStyle = 'arabic' | 'roman' | 'words';
+
+/** Matches Rust ChapterNumbering struct (serde camelCase fields) */
+export interface ChapterNumbering {
+  style: NumberStyle;
+  start: number;
+}
+
+/** Matches Rust BodyMode enum (serde snake_case) */
+export type BodyMode = 'full' | 'synopsis_only' | 'titles_only';
 
 /** Matches Rust CompileConfig struct (serde camelCase fields, snake_case enum values) */
 export interface CompileConfig {
@@ -17,6 +29,12 @@ export interface CompileConfig {
   outputFormat: OutputFormat;
   includeSynopsis: boolean;
   frontMatter: string;
+  chapterNumbering: ChapterNumbering;
+  bodyMode: BodyMode;
+  resolveLinks: boolean;
+  dropcaps: boolean;
+  renderWikiLinks: boolean;
+  wordsPerPage: number;
 }
 
 /** Matches Rust CompileOutput struct (serde camelCase fields) */
@@ -25,6 +43,8 @@ export interface CompileOutput {
   format: OutputFormat;
   chapterCount: number;
   wordCount: number;
+  pageEstimate: number;
+  manuscriptPageEstimate?: number;
 }
 
 /** Default compile config matching Rust Default impl */
@@ -38,5 +58,11 @@ export function defaultCompileConfig(): CompileConfig {
     outputFormat: 'markdown',
     includeSynopsis: false,
     frontMatter: '',
+    chapterNumbering: { style: 'arabic', start: 1 },
+    bodyMode: 'full',
+    resolveLinks: false,
+    dropcaps: false,
+    renderWikiLinks: false,
+    wordsPerPage: 250,
   };
 }
@@ -1,4 +1,11 @@
-export type FieldType = 'short_text' | 'long_text' | 'number' | 'select' | 'date' | 'boolean';
+export type FieldType =
+  | 'short_text'
+  | 'long_text'
+  | 'number'
+  | 'select'
+  | 'date'
+  | 'boolean'
+  | 'reference';
 
 export interface EntityField {
   name: string;
@@ -10,6 +17,7 @@ export interface EntityField {
   options?: string[]; // for select type
   min?: number; // for number type
   max?: number; // for number type
+  referenceSchema?: string; // for reference type
   defaultValue?: string | number | boolean;
 }
 
@@ -48,9 +56,59 @@ export interface EntityInstance {
   body: string;
 }
 
+export interface SaveEntityResult {
+  entity: EntityInstance;
+  warnings: string[];
+}
+
 export interface EntitySummary {
   title: string;
   slug: string;
   schemaType: string;
   tags: string[];
 }
+
+export interface TagCount {
+  tag: string;
+  count: number;
+}
+
+export interface ComparedEntity {
+  slug: string;
+  title: string;
+}
+
+export interface AxisComparison {
+  name: string;
+  min: number;
+  max: number;
+  values: number[];
+}
+
+export interface SpiderComparison {
+  schemaType: string;
+  entities: ComparedEntity[];
+  axes: AxisComparison[];
+}
+
+export interface EntityTemplate {
+  name: string;
+  schemaType: string;
+  fields: Record<string, unknown>;
+}
+
+export interface ValidationError {
+  field: string;
+  message: string;
+}
+
+export interface CsvRowError {
+  line: number;
+  title: string;
+  errors: ValidationError[];
+}
+
+export interface CsvImportReport {
+  imported: EntitySummary[];
+  failed: CsvRowError[];
+}
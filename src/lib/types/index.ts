@@ -1,9 +1,9 @@
 export type { ProjectManifest, RecentProject } from './project';
-export type { FieldType, EntityField, SpiderAxis, EntitySchema, SchemaSummary, EntityInstance, EntitySummary } from './entity';
-export type { ChapterStatus, ManuscriptConfig, Chapter, ChapterContent } from './manuscript';
-export type { CorkboardPosition, NoteEntry, NotesConfig, NoteContent } from './note';
+export type { FieldType, EntityField, SpiderAxis, EntitySchema, SchemaSummary, EntityInstance, EntitySummary, SaveEntityResult } from './entity';
+export type { ChapterStatus, KnownChapterStatus, ManuscriptConfig, Chapter, ChapterContent, ChapterStatusUpdate, TextStats, WordFrequency, ChapterWordCount, WordUsage } from './manuscript';
+export type { CorkboardPosition, NoteColor, NoteEntry, NotesConfig, NoteContent } from './note';
 export type { EditorTab, DocumentContent, WordCount } from './editor';
 export type { Theme, ViewMode, PaneConfig, UIState } from './ui';
 export type { WritingSession, SessionStats } from './session';
-export type { ChapterHeaderStyle, ChapterSeparator, OutputFormat, CompileConfig, CompileOutput } from './compile';
+export type { ChapterHeaderStyle, ChapterSeparator, OutputFormat, NumberStyle, ChapterNumbering, BodyMode, CompileConfig, CompileOutput } from './compile';
 export { defaultCompileConfig } from './compile';
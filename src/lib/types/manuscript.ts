@@ -1,4 +1,7 @@
-export type ChapterStatus = 'draft' | 'revised' | 'final';
+export type KnownChapterStatus = 'outline' | 'draft' | 'revised' | 'final';
+
+// Files may carry statuses this version doesn't know; the backend keeps them as-is.
+export type ChapterStatus = KnownChapterStatus | (string & {});
 
 export interface ManuscriptConfig {
   chapters: string[]; // ordered list of chapter slugs
@@ -19,3 +22,47 @@ export interface ChapterContent {
   frontmatter: Chapter;
   body: string;
 }
+
+export interface ChapterProgress {
+  slug: string;
+  title: string;
+  words: number;
+  targetWords?: number;
+  percentComplete?: number;
+}
+
+export interface ManuscriptProgress {
+  totalWords: number;
+  totalTargetWords: number;
+  percentComplete?: number;
+  chapters: ChapterProgress[];
+}
+
+export interface ChapterStatusUpdate {
+  updated: string[];
+  notFound: string[];
+}
+
+export interface WordFrequency {
+  word: string;
+  count: number;
+}
+
+export interface TextStats {
+  wordCount: number;
+  sentenceCount: number;
+  avgWordsPerSentence: number;
+  fleschReadingEase: number;
+  topWords: WordFrequency[];
+}
+
+export interface ChapterWordCount {
+  slug: string;
+  count: number;
+}
+
+export interface WordUsage {
+  word: string;
+  total: number;
+  chapters: ChapterWordCount[];
+}
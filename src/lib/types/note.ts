@@ -3,6 +3,11 @@ export interface CorkboardPosition {
   y: number;
 }
 
+export interface NoteColor {
+  name: string;
+  hex: string;
+}
+
 export interface NoteEntry {
   slug: string;
   title: string;
@@ -6,6 +6,12 @@ export interface WritingSession {
   wordsWritten: number;
   chapterSlug: string;
   sprintGoal?: number;
+  pausedSeconds?: number;
+  pausedAt?: string;        // ISO 8601, set while paused
+  startWordCount?: number;  // chapter word count when the session started
+  netWordChange?: number;   // net start-to-end change, negative after net deletions
+  wordsAdded?: number;      // gross words added across saves
+  wordsRemoved?: number;    // gross words removed across saves
 }
 
 export interface SessionStats {
@@ -19,4 +25,32 @@ export interface SessionStats {
   monthlyAverage: number;
   bestDayWords: number;
   bestDayDate?: string;
+  wordsPerMinute: number;
+  wordsToday: number;
+  goalMetToday: boolean;
+  streakAtRisk: boolean;
+}
+
+export interface SprintProgress {
+  words: number;
+  goal?: number;
+  percent?: number;
+  goalMet: boolean;
+  elapsedMinutes: number;
+}
+
+export interface DailyWords {
+  date: string;
+  words: number;
+}
+
+export interface MonthlyReport {
+  year: number;
+  month: number;
+  days: DailyWords[];
+  totalWords: number;
+  totalMinutes: number;
+  writingDays: number;
+  bestDayWords: number;
+  bestDayDate?: string;
 }